@@ -1,13 +1,17 @@
 import { BN, Program } from "@coral-xyz/anchor";
-import { PublicKey, SystemProgram, TransactionSignature } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, TransactionSignature } from "@solana/web3.js";
 import { TokenVesting } from "../../../target/types/token_vesting";
+import { getInvitePda } from "./invite";
+import { Authorization } from "./utils";
 
 export const joinOrganization = async (
   program: Program<TokenVesting>,
   employeeSigner: PublicKey,
   orgId: BN,
   employeeName: string,
-  employeePosition: string
+  employeePosition: string,
+  // Keypair of a code invite, which co-signs the join; wallet invites need nothing extra
+  inviteCode: Keypair | null = null
 ): Promise<TransactionSignature> => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
@@ -23,6 +27,15 @@ export const joinOrganization = async (
     [Buffer.from("employee"), employeeSigner.toBuffer(), orgId.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
+
+  // Invite-only organizations need the invite and its creator, who gets the invite's rent back
+  const organization = await program.account.organization.fetch(organizationPda);
+  let invite: PublicKey | null = null;
+  let inviteCreator: PublicKey | null = null;
+  if (!organization.openEnrollment) {
+    invite = getInvitePda(program.programId, orgId, inviteCode?.publicKey ?? employeeSigner);
+    inviteCreator = (await program.account.invite.fetch(invite)).createdBy;
+  }
   
   const tx = await program.methods
     .joinOrganization(orgId, employeeName, employeePosition)
@@ -31,8 +44,12 @@ export const joinOrganization = async (
       organization: organizationPda,
       employee: employeePda,
       employeeSigner,
+      invite,
+      inviteCode: inviteCode?.publicKey ?? null,
+      inviteCreator,
       systemProgram: SystemProgram.programId,
     })
+    .signers(inviteCode ? [inviteCode] : [])
     .rpc();
   await program.provider.connection.confirmTransaction(tx, "finalized");
   return tx;
@@ -82,9 +99,11 @@ export const fetchEmployee = async (
 
 export const removeEmployee = async (
   program: Program<TokenVesting>,
+  // The owner, or an HR or Admin member
   owner: PublicKey,
   orgId: BN,
-  employee: PublicKey
+  employee: PublicKey,
+  authorization: Authorization = {}
 ): Promise<TransactionSignature> => {
   const [organizationPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("organization"), orgId.toArrayLike(Buffer, "le", 8)],
@@ -102,6 +121,8 @@ export const removeEmployee = async (
       organization: organizationPda,
       employee: employeePda,
       owner,
+      role: authorization.role ?? null,
+      proposal: authorization.proposal ?? null,
     })
     .rpc();
 
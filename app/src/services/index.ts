@@ -4,6 +4,7 @@ export * from './provider';
 export * from './program';
 export * from './organization';
 export * from './employee';
+export * from './invite';
 export * from './vesting';
 export * from './dashboard';
 export * from './utils';
\ No newline at end of file
@@ -0,0 +1,87 @@
+import { BN, Program } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, TransactionSignature } from "@solana/web3.js";
+import { TokenVesting } from "../../../target/types/token_vesting";
+
+// `invitee` is the invited wallet, or the public key of an invite code keypair shared off-chain
+export const getInvitePda = (programId: PublicKey, orgId: BN, invitee: PublicKey): PublicKey => {
+  const [invitePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("invite"), orgId.toArrayLike(Buffer, "le", 8), invitee.toBuffer()],
+    programId
+  );
+  return invitePda;
+};
+
+export const createInvite = async (
+  program: Program<TokenVesting>,
+  // The owner, or an HR or Admin member
+  authority: PublicKey,
+  orgId: BN,
+  invitee: PublicKey,
+  isCode: boolean,
+  expiresAt: BN,
+  role: PublicKey | null = null
+): Promise<TransactionSignature> => {
+  const [organizationPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("organization"), orgId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const invitePda = getInvitePda(program.programId, orgId, invitee);
+  // An expired invite for the same invitee is replaced, refunding its creator
+  const expiredInvite = await program.account.invite.fetchNullable(invitePda);
+
+  const tx = await program.methods
+    .createInvite(orgId, invitee, isCode, expiresAt)
+    .accountsPartial({
+      organization: organizationPda,
+      invite: invitePda,
+      authority,
+      role,
+      previousInviteCreator: expiredInvite?.createdBy ?? null,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+  await program.provider.connection.confirmTransaction(tx, "finalized");
+  return tx;
+};
+
+export const fetchInvite = async (
+  program: Program<TokenVesting>,
+  orgId: BN,
+  invitee: PublicKey
+) => {
+  try {
+    return await program.account.invite.fetch(getInvitePda(program.programId, orgId, invitee));
+  } catch (error) {
+    console.error("Error fetching invite:", error);
+    return null;
+  }
+};
+
+export const cancelInvite = async (
+  program: Program<TokenVesting>,
+  // The invite's creator, the owner, or an HR or Admin member
+  authority: PublicKey,
+  orgId: BN,
+  invitee: PublicKey,
+  role: PublicKey | null = null
+): Promise<TransactionSignature> => {
+  const [organizationPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("organization"), orgId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const invitePda = getInvitePda(program.programId, orgId, invitee);
+  const invite = await program.account.invite.fetch(invitePda);
+
+  const tx = await program.methods
+    .cancelInvite(orgId, invitee)
+    .accountsPartial({
+      organization: organizationPda,
+      invite: invitePda,
+      inviteCreator: invite.createdBy,
+      authority,
+      role,
+    })
+    .rpc();
+  await program.provider.connection.confirmTransaction(tx, "finalized");
+  return tx;
+};
\ No newline at end of file
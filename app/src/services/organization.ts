@@ -1,6 +1,7 @@
 import { BN, Program } from "@coral-xyz/anchor";
 import { PublicKey, SystemProgram, TransactionSignature } from "@solana/web3.js";
 import { TokenVesting } from "../../../target/types/token_vesting";
+import { Authorization } from "./utils";
 
 export const createOrganization = async (
   program: Program<TokenVesting>,
@@ -71,4 +72,28 @@ export const isOrganizationOwner = async (
   } catch (error) {
     return false;
   }
+};
+
+export const setOpenEnrollment = async (
+  program: Program<TokenVesting>,
+  owner: PublicKey,
+  orgId: BN,
+  openEnrollment: boolean,
+  authorization: Authorization = {}
+): Promise<TransactionSignature> => {
+  const [organizationPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("organization"), orgId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const tx = await program.methods
+    .setOpenEnrollment(orgId, openEnrollment)
+    .accountsPartial({
+      organization: organizationPda,
+      owner,
+      proposal: authorization.proposal ?? null,
+    })
+    .rpc();
+  await program.provider.connection.confirmTransaction(tx, "finalized");
+  return tx;
 };
\ No newline at end of file
@@ -21,4 +21,11 @@ export const getTokenBalance = async (
     console.error("Error getting token balance:", error);
     return new BN(0);
   }
+};
+
+// Multisig organizations execute owner actions through an approved proposal
+export type Authorization = {
+  proposal?: PublicKey;
+  // Members act through their role account
+  role?: PublicKey;
 };
\ No newline at end of file
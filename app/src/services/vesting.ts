@@ -1,7 +1,50 @@
 import { BN, Program } from "@coral-xyz/anchor";
 import { PublicKey, SystemProgram, TransactionSignature } from "@solana/web3.js";
-import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { TokenVesting } from "../../../target/types/token_vesting";
+import { Authorization } from "./utils";
+
+// Schedule ids are globally unique, so the employee is not part of the seeds
+export const getVestingSchedulePda = (
+  programId: PublicKey,
+  orgId: BN,
+  tokenMint: PublicKey,
+  scheduleId: BN
+): PublicKey => {
+  const [vestingSchedulePda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vesting_schedule"),
+      orgId.toArrayLike(Buffer, "le", 8),
+      tokenMint.toBuffer(),
+      scheduleId.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+  return vestingSchedulePda;
+};
+
+// Token-2022 mints are owned by their own program, which also changes the associated token addresses
+const getTokenProgramId = async (program: Program<TokenVesting>, tokenMint: PublicKey): Promise<PublicKey> => {
+  const mintInfo = await program.provider.connection.getAccountInfo(tokenMint);
+  if (!mintInfo) {
+    throw new Error("Token mint not found");
+  }
+  return mintInfo.owner;
+};
+
+export type VestingScheduleOptions = Authorization & {
+  // Unlock frequency in seconds; 0 (the default) vests continuously
+  periodSeconds?: BN;
+  cliffUnlockAmount?: BN;
+  initialUnlockAmount?: BN;
+  // Unlock table account for table-based schedules
+  unlockTable?: PublicKey;
+  // One amount per milestone tranche for milestone-based schedules
+  milestoneAmounts?: BN[];
+  terminationPolicy?: { goodLeaverExtraMonths: number; badLeaverForfeitPercentage: number };
+};
+
+export type TerminationMode = { goodLeaver: Record<string, never> } | { badLeaver: Record<string, never> };
 
 export const initializeVestingSchedule = async (
   program: Program<TokenVesting>,
@@ -13,7 +56,8 @@ export const initializeVestingSchedule = async (
   startTime: BN,
   cliffTime: BN,
   endTime: BN,
-  revocable: boolean
+  revocable: boolean,
+  options: VestingScheduleOptions = {}
 ): Promise<TransactionSignature> => {
   const connection = program.provider.connection;
 
@@ -40,23 +84,16 @@ export const initializeVestingSchedule = async (
   const scheduleId = new BN(programState.totalVestingSchedules).add(new BN(1));
 
   // 5. Vesting schedule PDA
-  const [vestingSchedulePda] = PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("vesting_schedule"),
-      orgId.toArrayLike(Buffer, "le", 8),
-      employee.toBuffer(),
-      tokenMint.toBuffer(),
-      scheduleId.toArrayLike(Buffer, "le", 8),
-    ],
-    program.programId
-  );
+  const vestingSchedulePda = getVestingSchedulePda(program.programId, orgId, tokenMint, scheduleId);
+
+  const tokenProgram = await getTokenProgramId(program, tokenMint);
 
   // 6. Vesting token account (ATA for the vesting schedule PDA)
   const vestingTokenAccount = getAssociatedTokenAddressSync(
     tokenMint,
     vestingSchedulePda,
     true, // PDA is off-curve
-    TOKEN_PROGRAM_ID,
+    tokenProgram,
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
@@ -65,7 +102,7 @@ export const initializeVestingSchedule = async (
     tokenMint,
     employer,
     false,
-    TOKEN_PROGRAM_ID,
+    tokenProgram,
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
@@ -82,7 +119,12 @@ export const initializeVestingSchedule = async (
       startTime,
       cliffTime,
       endTime,
-      revocable
+      options.periodSeconds ?? new BN(0),
+      options.cliffUnlockAmount ?? new BN(0),
+      options.initialUnlockAmount ?? new BN(0),
+      options.milestoneAmounts ?? [],
+      revocable,
+      options.terminationPolicy ?? { goodLeaverExtraMonths: 0, badLeaverForfeitPercentage: 0 }
     )
     .accountsPartial({
       programState: programStatePda,
@@ -93,7 +135,10 @@ export const initializeVestingSchedule = async (
       employerTokenAccount,
       tokenMint,
       employer,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      role: options.role ?? null,
+      proposal: options.proposal ?? null,
+      unlockTable: options.unlockTable ?? null,
+      tokenProgram: tokenProgram,
       systemProgram: SystemProgram.programId,
       rent: new PublicKey("SysvarRent111111111111111111111111111111111"),
     })
@@ -140,17 +185,9 @@ export const fetchVestingSchedule = async (
   scheduleId: BN
 ) => {
   try {
-    const [vestingSchedulePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("vesting_schedule"),
-        orgId.toArrayLike(Buffer, "le", 8),
-        employee.toBuffer(),
-        tokenMint.toBuffer(),
-        scheduleId.toArrayLike(Buffer, "le", 8)
-      ],
-      program.programId
-    );
-    return await program.account.vestingSchedule.fetch(vestingSchedulePda);
+    const vestingSchedulePda = getVestingSchedulePda(program.programId, orgId, tokenMint, scheduleId);
+    const vestingSchedule = await program.account.vestingSchedule.fetch(vestingSchedulePda);
+    return vestingSchedule.employee.equals(employee) ? vestingSchedule : null;
   } catch (error) {
     console.error("Error fetching vesting schedule:", error);
     return null;
@@ -162,25 +199,21 @@ export const claimTokens = async (
   orgId: BN,
   employee: PublicKey,
   tokenMint: PublicKey,
-  scheduleId: BN
+  scheduleId: BN,
+  amount: BN | null = null,
+  // The employee, or their claim delegate
+  claimer: PublicKey = employee
 ): Promise<TransactionSignature> => {
   try {
-    const [vestingSchedulePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("vesting_schedule"),
-        orgId.toArrayLike(Buffer, "le", 8),
-        employee.toBuffer(),
-        tokenMint.toBuffer(),
-        scheduleId.toArrayLike(Buffer, "le", 8)
-      ],
-      program.programId
-    );
+    const vestingSchedulePda = getVestingSchedulePda(program.programId, orgId, tokenMint, scheduleId);
+    const { unlockTable } = await program.account.vestingSchedule.fetch(vestingSchedulePda);
+    const tokenProgram = await getTokenProgramId(program, tokenMint);
     
     const vestingTokenAccount = getAssociatedTokenAddressSync(
       tokenMint,
       vestingSchedulePda,
       true,
-      TOKEN_PROGRAM_ID,
+      tokenProgram,
       ASSOCIATED_TOKEN_PROGRAM_ID
     );
     
@@ -188,18 +221,21 @@ export const claimTokens = async (
       tokenMint,
       employee,
       false,
-      TOKEN_PROGRAM_ID,
+      tokenProgram,
       ASSOCIATED_TOKEN_PROGRAM_ID
     );
     
     const tx = await program.methods
-      .claimTokens()
+      .claimTokens(amount)
       .accountsPartial({
         vestingSchedule: vestingSchedulePda,
         vestingTokenAccount,
         employeeTokenAccount,
         employee,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        claimer,
+        tokenMint,
+        tokenProgram: tokenProgram,
+        unlockTable,
       })
       .rpc();
       
@@ -214,26 +250,18 @@ export const claimTokens = async (
 export const getClaimableAmount = async (
   program: Program<TokenVesting>,
   orgId: BN,
-  employee: PublicKey,
   tokenMint: PublicKey,
   scheduleId: BN
 ): Promise<BN> => {
   try {
-    const [vestingSchedulePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("vesting_schedule"),
-        orgId.toArrayLike(Buffer, "le", 8),
-        employee.toBuffer(),
-        tokenMint.toBuffer(),
-        scheduleId.toArrayLike(Buffer, "le", 8)
-      ],
-      program.programId
-    );
+    const vestingSchedulePda = getVestingSchedulePda(program.programId, orgId, tokenMint, scheduleId);
+    const { unlockTable } = await program.account.vestingSchedule.fetch(vestingSchedulePda);
 
     const result = await program.methods
       .getClaimableAmount()
       .accountsPartial({
         vestingSchedule: vestingSchedulePda,
+        unlockTable,
       })
       .view();
     
@@ -246,33 +274,30 @@ export const getClaimableAmount = async (
 
 export const revokeVestingSchedule = async (
   program: Program<TokenVesting>,
-  employer: PublicKey,
+  // The owner, or a member acting through their role
+  authority: PublicKey,
   orgId: BN,
-  employee: PublicKey,
   tokenMint: PublicKey,
-  scheduleId: BN
+  scheduleId: BN,
+  mode: TerminationMode = { goodLeaver: {} },
+  authorization: Authorization = {}
 ): Promise<TransactionSignature> => {
   const [organizationPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("organization"), orgId.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
-  const [vestingSchedulePda] = PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("vesting_schedule"),
-      orgId.toArrayLike(Buffer, "le", 8),
-      employee.toBuffer(),
-      tokenMint.toBuffer(),
-      scheduleId.toArrayLike(Buffer, "le", 8)
-    ],
-    program.programId
-  );
+  const vestingSchedulePda = getVestingSchedulePda(program.programId, orgId, tokenMint, scheduleId);
+  const { unlockTable } = await program.account.vestingSchedule.fetch(vestingSchedulePda);
+  // Unvested tokens always return to the current organization owner
+  const { owner: employer } = await program.account.organization.fetch(organizationPda);
+  const tokenProgram = await getTokenProgramId(program, tokenMint);
 
   const vestingTokenAccount = getAssociatedTokenAddressSync(
     tokenMint,
     vestingSchedulePda,
     true,
-    TOKEN_PROGRAM_ID,
+    tokenProgram,
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
@@ -280,18 +305,24 @@ export const revokeVestingSchedule = async (
     tokenMint,
     employer,
     false,
-    TOKEN_PROGRAM_ID,
+    tokenProgram,
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
   const tx = await program.methods
-    .revokeVesting()
+    .revokeVesting(mode)
     .accountsPartial({
+      organization: organizationPda,
       vestingSchedule: vestingSchedulePda,
       vestingTokenAccount,
       employerTokenAccount,
       employer,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      authority,
+      tokenMint,
+      tokenProgram: tokenProgram,
+      role: authorization.role ?? null,
+      proposal: authorization.proposal ?? null,
+      unlockTable,
     })
     .rpc();
 
@@ -1,7 +1,14 @@
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 pub const SECONDS_PER_DAY: i64 = 86400;
+pub const SECONDS_PER_MONTH: i64 = 30 * SECONDS_PER_DAY;
 pub const MIN_VESTING_DURATION: i64 = SECONDS_PER_DAY;
 pub const MAX_EMPLOYEE_NAME_LENGTH: usize = 50;
 pub const MAX_EMPLOYEE_POSITION_LENGTH: usize = 50;
 pub const MAX_ORG_NAME_LENGTH: usize = 100;
-pub const MAX_EMPLOYEES_PER_ORG: u64 = 1000;
\ No newline at end of file
+pub const MAX_EMPLOYEES_PER_ORG: u64 = 1000;
+pub const MAX_UNLOCK_CHECKPOINTS: usize = 32;
+pub const MAX_MILESTONES: usize = 8;
+pub const MAX_PAUSE_INTERVALS: usize = 8;
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MIN_CRANK_TIP_INTERVAL: i64 = SECONDS_PER_DAY;
+pub const MAX_CRANK_TIP_LAMPORTS: u64 = 1_000_000;
\ No newline at end of file
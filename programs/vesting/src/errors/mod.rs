@@ -48,4 +48,94 @@ pub enum ErrorCode {
     OrganizationNotActive,
     #[msg("Employee is not active")]
     EmployeeNotActive,
+    #[msg("Vesting period must be non-negative and no longer than cliff_time..end_time")]
+    InvalidVestingPeriod,
+    #[msg("Cliff unlock amount cannot exceed total amount")]
+    InvalidCliffUnlockAmount,
+    #[msg("Initial unlock plus cliff unlock amount cannot exceed total amount")]
+    InvalidInitialUnlockAmount,
+    #[msg("Unlock table checkpoints must be non-empty, time-ordered and cumulative")]
+    InvalidUnlockTable,
+    #[msg("Unlock table does not match the vesting schedule")]
+    UnlockTableMismatch,
+    #[msg("Milestone amounts must be non-zero and sum to the total amount")]
+    InvalidMilestones,
+    #[msg("Vesting schedule is not milestone-based")]
+    NotMilestoneSchedule,
+    #[msg("Milestone index out of range")]
+    InvalidMilestoneIndex,
+    #[msg("Milestone already attested")]
+    MilestoneAlreadyAttested,
+    #[msg("Only the organization's milestone attester can perform this action")]
+    UnauthorizedMilestoneAttester,
+    #[msg("Vesting schedule still has vested tokens left to claim")]
+    VestingScheduleNotCompleted,
+    #[msg("Failed to calculate the mint's transfer fee")]
+    TransferFeeCalculationFailed,
+    #[msg("Token accounts are required for SPL token vesting schedules")]
+    TokenAccountsRequired,
+    #[msg("Unlock-table and milestone schedules cannot be topped up")]
+    TopUpNotSupported,
+    #[msg("Unlock-table and milestone schedules cannot have their timing amended")]
+    AmendmentNotSupported,
+    #[msg("Amendment would reduce the already vested amount")]
+    AmendmentReducesVestedAmount,
+    #[msg("Vesting schedule is already paused")]
+    VestingAlreadyPaused,
+    #[msg("Vesting schedule is not paused")]
+    VestingNotPaused,
+    #[msg("Vesting schedule has reached the maximum number of pauses")]
+    TooManyPauses,
+    #[msg("Milestone schedules cannot be paused")]
+    PauseNotSupported,
+    #[msg("Acceleration must be 1-100 percent or at least one month")]
+    InvalidAcceleration,
+    #[msg("No unvested amount left to accelerate")]
+    NothingToAccelerate,
+    #[msg("Bad leaver forfeit percentage cannot exceed 100")]
+    InvalidTerminationPolicy,
+    #[msg("New beneficiary must be a different, non-default key")]
+    InvalidBeneficiary,
+    #[msg("Destination token account does not hold the vesting mint")]
+    InvalidDestinationTokenAccount,
+    #[msg("Claim amount must be non-zero and no more than the claimable amount")]
+    InvalidClaimAmount,
+    #[msg("Auto-distribution is not enabled for this vesting schedule")]
+    AutoDistributeDisabled,
+    #[msg("Only the employee or their claim delegate can claim")]
+    UnauthorizedClaimer,
+    #[msg("Multisig organizations must execute this action through an approved proposal")]
+    MultisigApprovalRequired,
+    #[msg("Organization is not a multisig organization")]
+    MultisigNotEnabled,
+    #[msg("Threshold must be between 1 and the number of distinct signers")]
+    InvalidMultisigConfig,
+    #[msg("Signer is not part of the organization's multisig")]
+    NotMultisigSigner,
+    #[msg("Proposal was already approved by this signer")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal was already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal does not have enough approvals")]
+    ProposalNotApproved,
+    #[msg("Proposal does not match the action being executed")]
+    ProposalActionMismatch,
+    #[msg("Signer does not hold a role permitting this action")]
+    UnauthorizedRole,
+    #[msg("Signer is not the pending organization owner")]
+    NotPendingOwner,
+    #[msg("Organization is invite-only")]
+    InviteRequired,
+    #[msg("Invite is not valid for this organization or signer")]
+    InvalidInvite,
+    #[msg("Invite has expired")]
+    InviteExpired,
+    #[msg("Invite has not expired yet")]
+    InviteStillActive,
+    #[msg("Organization does not hold enough crank tip lamports above its rent-exempt minimum")]
+    InsufficientCrankTipBalance,
+    #[msg("Crank tip exceeds the per-crank maximum")]
+    CrankTipTooLarge,
+    #[msg("The same vesting schedule was passed more than once")]
+    DuplicateVestingSchedule,
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{AccelerationMode, Organization, Proposal, ProposalAction, VestingSchedule},
+    utils::{find_unlock_table, hash_vesting_schedules, load_unlock_tables},
+};
+
+/// Change-of-control acceleration: applies the same acceleration to every live schedule passed in.
+pub fn accelerate_organization_vesting<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AccelerateOrganizationVestingCtx<'info>>,
+    org_id: u64,
+    mode: AccelerationMode,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let mut schedule_keys = BTreeSet::new();
+    let mut vesting_schedules = Vec::new();
+    for info in ctx.remaining_accounts {
+        let Ok(vesting_schedule) = Account::<VestingSchedule>::try_from(info) else {
+            continue;
+        };
+        // A repeated schedule would be accelerated once per occurrence
+        if !schedule_keys.insert(info.key()) {
+            return Err(ErrorCode::DuplicateVestingSchedule.into());
+        }
+        vesting_schedules.push(vesting_schedule);
+    }
+
+    let schedule_keys: Vec<Pubkey> = vesting_schedules.iter().map(|schedule| schedule.key()).collect();
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::AccelerateOrganizationVesting {
+            mode,
+            schedules_hash: hash_vesting_schedules(&schedule_keys),
+        },
+    )?;
+    let organization = &ctx.accounts.organization;
+
+    // Unlock tables of table-based schedules are appended after the schedules
+    let unlock_tables = load_unlock_tables(ctx.remaining_accounts);
+
+    let mut accelerated_schedules = 0;
+    let mut total_accelerated_amount: u64 = 0;
+    for mut vesting_schedule in vesting_schedules {
+        if vesting_schedule.org_id != org_id {
+            return Err(ErrorCode::OrganizationNotFound.into());
+        }
+        if vesting_schedule.revoked {
+            continue;
+        }
+
+        let unlock_table = find_unlock_table(&vesting_schedule, &unlock_tables)?;
+
+        let accelerated_amount = vesting_schedule.accelerate(mode, current_time, unlock_table)?;
+        if accelerated_amount == 0 {
+            continue;
+        }
+        vesting_schedule.exit(&crate::ID)?;
+
+        accelerated_schedules += 1;
+        total_accelerated_amount = total_accelerated_amount.saturating_add(accelerated_amount);
+    }
+
+    msg!(
+        "Accelerated {} vesting schedules of organization '{}' by {} tokens in total",
+        accelerated_schedules,
+        organization.name,
+        total_accelerated_amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct AccelerateOrganizationVestingCtx<'info> {
+    #[account(
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    // remaining_accounts: writable VestingSchedules, then UnlockTable for table-based schedules
+}
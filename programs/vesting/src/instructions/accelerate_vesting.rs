@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{AccelerationMode, Organization, Proposal, ProposalAction, UnlockTable, VestingSchedule},
+};
+
+pub fn accelerate_vesting(ctx: Context<AccelerateVestingCtx>, mode: AccelerationMode) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::AccelerateVesting { vesting_schedule: vesting_schedule.key(), mode },
+    )?;
+    if vesting_schedule.revoked {
+        return Err(ErrorCode::VestingScheduleRevoked.into());
+    }
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
+
+    let accelerated_amount = vesting_schedule.accelerate(mode, current_time, ctx.accounts.unlock_table.as_deref())?;
+    if accelerated_amount == 0 {
+        return Err(ErrorCode::NothingToAccelerate.into());
+    }
+
+    msg!(
+        "Accelerated {} tokens of vesting schedule {}, {} accelerated in total",
+        accelerated_amount,
+        vesting_schedule.schedule_id,
+        vesting_schedule.accelerated_amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AccelerateVestingCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
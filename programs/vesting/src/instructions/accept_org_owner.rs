@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{errors::ErrorCode, states::Organization};
+
+pub fn accept_org_owner(ctx: Context<AcceptOrgOwnerCtx>, _org_id: u64) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let new_owner = ctx.accounts.new_owner.key();
+
+    if organization.pending_owner != Some(new_owner) {
+        return Err(ErrorCode::NotPendingOwner.into());
+    }
+
+    let previous_owner = organization.owner;
+    organization.owner = new_owner;
+    organization.pending_owner = None;
+    // The attester defaults to the owner and should not stay with the departing key
+    if organization.milestone_attester == previous_owner {
+        organization.milestone_attester = new_owner;
+    }
+
+    msg!(
+        "Ownership of organization '{}' transferred from {} to {}",
+        organization.name,
+        previous_owner,
+        new_owner
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct AcceptOrgOwnerCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub new_owner: Signer<'info>,
+}
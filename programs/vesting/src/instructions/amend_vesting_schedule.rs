@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction, VestingAmendment, VestingSchedule},
+};
+
+pub fn amend_vesting_schedule(
+    ctx: Context<AmendVestingScheduleCtx>,
+    new_cliff_time: i64,
+    new_end_time: i64,
+) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let vesting_amendment = &mut ctx.accounts.vesting_amendment;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.employer.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::AmendVestingSchedule {
+            vesting_schedule: vesting_schedule.key(),
+            new_cliff_time,
+            new_end_time,
+        },
+    )?;
+    if vesting_schedule.revoked {
+        return Err(ErrorCode::VestingScheduleRevoked.into());
+    }
+    if vesting_schedule.unlock_table.is_some() || vesting_schedule.is_milestone_based() {
+        return Err(ErrorCode::AmendmentNotSupported.into());
+    }
+    if vesting_schedule.start_time >= new_cliff_time || new_cliff_time >= new_end_time {
+        return Err(ErrorCode::InvalidTimeParameters.into());
+    }
+    if new_end_time - vesting_schedule.start_time < MIN_VESTING_DURATION {
+        return Err(ErrorCode::VestingDurationTooShort.into());
+    }
+    if vesting_schedule.period_seconds > new_end_time - new_cliff_time {
+        return Err(ErrorCode::InvalidVestingPeriod.into());
+    }
+
+    let previous_cliff_time = vesting_schedule.cliff_time;
+    let previous_end_time = vesting_schedule.end_time;
+    let vested_before = vesting_schedule.calculate_vested_amount(current_time, None);
+
+    vesting_schedule.cliff_time = new_cliff_time;
+    vesting_schedule.end_time = new_end_time;
+    if vesting_schedule.calculate_vested_amount(current_time, None) < vested_before {
+        return Err(ErrorCode::AmendmentReducesVestedAmount.into());
+    }
+    vesting_schedule.amendment_count += 1;
+
+    vesting_amendment.vesting_schedule = vesting_schedule.key();
+    vesting_amendment.amendment_index = vesting_schedule.amendment_count;
+    vesting_amendment.previous_cliff_time = previous_cliff_time;
+    vesting_amendment.previous_end_time = previous_end_time;
+    vesting_amendment.new_cliff_time = new_cliff_time;
+    vesting_amendment.new_end_time = new_end_time;
+    vesting_amendment.amended_at = current_time;
+
+    msg!(
+        "Vesting schedule {} amended: cliff {} -> {}, end {} -> {}",
+        vesting_schedule.schedule_id,
+        previous_cliff_time,
+        new_cliff_time,
+        previous_end_time,
+        new_end_time
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AmendVestingScheduleCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = vesting_schedule.employee == employee.key() @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        seeds = [b"organization", vesting_schedule.org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        init,
+        payer = employer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + VestingAmendment::INIT_SPACE,
+        seeds = [
+            b"vesting_amendment",
+            vesting_schedule.key().as_ref(),
+            (vesting_schedule.amendment_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_amendment: Account<'info, VestingAmendment>,
+
+    // The owner, or a multisig signer executing the proposal
+    #[account(mut)]
+    pub employer: Signer<'info>,
+    // The employee co-signs to consent to the new terms
+    pub employee: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
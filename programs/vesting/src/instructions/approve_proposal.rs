@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{Organization, Proposal},
+};
+
+pub fn approve_proposal(ctx: Context<ApproveProposalCtx>) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let proposal = &mut ctx.accounts.proposal;
+    let approver = ctx.accounts.approver.key();
+
+    if !organization.is_multisig_signer(&approver) {
+        return Err(ErrorCode::NotMultisigSigner.into());
+    }
+    if proposal.executed {
+        return Err(ErrorCode::ProposalAlreadyExecuted.into());
+    }
+    if proposal.approvals.contains(&approver) {
+        return Err(ErrorCode::ProposalAlreadyApproved.into());
+    }
+
+    // Approvals from keys removed from the signer set no longer count
+    proposal.approvals.retain(|key| organization.is_multisig_signer(key));
+    proposal.approvals.push(approver);
+
+    msg!(
+        "Proposal {} approved by {} ({} of {} approvals)",
+        proposal.proposal_id,
+        approver,
+        proposal.approvals.len(),
+        organization.multisig_threshold
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposalCtx<'info> {
+    #[account(
+        seeds = [b"organization", proposal.org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            proposal.org_id.to_le_bytes().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub approver: Signer<'info>,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction, VestingSchedule},
+};
+
+pub fn attest_milestone(ctx: Context<AttestMilestoneCtx>, milestone_index: u8) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    // The attester defaults to the owner, whose attestations need a proposal in multisig organizations
+    if organization.milestone_attester == organization.owner {
+        organization.authorize_owner_action(
+            &ctx.accounts.attester.key(),
+            ctx.accounts.proposal.as_deref_mut(),
+            &ProposalAction::AttestMilestone { vesting_schedule: vesting_schedule.key(), milestone_index },
+        )?;
+    } else if organization.milestone_attester != ctx.accounts.attester.key() {
+        return Err(ErrorCode::UnauthorizedMilestoneAttester.into());
+    }
+    if vesting_schedule.revoked {
+        return Err(ErrorCode::VestingScheduleRevoked.into());
+    }
+    if !vesting_schedule.is_milestone_based() {
+        return Err(ErrorCode::NotMilestoneSchedule.into());
+    }
+
+    let schedule_id = vesting_schedule.schedule_id;
+    let milestone = vesting_schedule
+        .milestones
+        .get_mut(milestone_index as usize)
+        .ok_or(ErrorCode::InvalidMilestoneIndex)?;
+    if milestone.attested_at.is_some() {
+        return Err(ErrorCode::MilestoneAlreadyAttested.into());
+    }
+    milestone.attested_at = Some(Clock::get()?.unix_timestamp);
+
+    msg!(
+        "Milestone {} attested, unlocking {} tokens of vesting schedule {}",
+        milestone_index,
+        milestone.amount,
+        schedule_id
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AttestMilestoneCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // The attester, or a multisig signer executing the proposal while the owner attests
+    pub attester: Signer<'info>,
+
+    // Only for multisig organizations whose owner attests
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
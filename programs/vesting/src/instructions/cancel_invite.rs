@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{Invite, OrgRole, Organization, Permission},
+};
+
+/// Withdraws an invite, returning its rent to whoever created it.
+pub fn cancel_invite(ctx: Context<CancelInviteCtx>, _org_id: u64, _invitee: Pubkey) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let invite = &ctx.accounts.invite;
+    let authority = ctx.accounts.authority.key();
+
+    if authority != invite.created_by {
+        organization.authorize_member(&authority, ctx.accounts.role.as_deref(), Permission::ManageEmployees)?;
+    }
+
+    msg!("Invite to organization '{}' for {} cancelled", organization.name, invite.invitee);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64, invitee: Pubkey)]
+pub struct CancelInviteCtx<'info> {
+    #[account(
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [b"invite", org_id.to_le_bytes().as_ref(), invitee.as_ref()],
+        bump,
+        close = invite_creator
+    )]
+    pub invite: Account<'info, Invite>,
+
+    /// CHECK: Receives the invite's rent
+    #[account(mut, address = invite.created_by @ ErrorCode::InvalidInvite)]
+    pub invite_creator: UncheckedAccount<'info>,
+
+    // The invite's creator, the owner, or an HR or Admin member
+    pub authority: Signer<'info>,
+
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+}
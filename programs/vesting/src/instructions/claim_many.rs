@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::{
+    ClaimSummary,
+    errors::ErrorCode,
+    states::VestingSchedule,
+    utils::{find_unlock_table, load_unlock_tables, pay_out_claim, VestingScheduleSeeds},
+};
+
+pub fn claim_many<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimManyCtx<'info>>,
+) -> Result<Vec<ClaimSummary>> {
+    let employee = &ctx.accounts.employee;
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut summaries = Vec::new();
+
+    // Unlock tables of table-based schedules are appended after the schedule groups
+    let unlock_tables = load_unlock_tables(ctx.remaining_accounts);
+
+    // Expect remaining_accounts in groups: [VestingSchedule] for native SOL schedules,
+    // [VestingSchedule, vault, mint, destination token account, token program] otherwise
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    while let Some(sched_info) = remaining_accounts.next() {
+        let Ok(mut vesting_schedule) = Account::<VestingSchedule>::try_from(sched_info) else {
+            break;
+        };
+        if vesting_schedule.employee != employee.key() {
+            return Err(ErrorCode::UnauthorizedEmployee.into());
+        }
+
+        let unlock_table = find_unlock_table(&vesting_schedule, &unlock_tables)?;
+        let claim_amount = vesting_schedule.calculate_claimable_amount(current_time, unlock_table);
+
+        if vesting_schedule.native {
+            if claim_amount > 0 {
+                // Lamport payouts are not signed with the schedule's seeds, so no bump is needed
+                pay_out_claim(&mut vesting_schedule, 0, None, None, None, employee.to_account_info(), None, claim_amount)?;
+                vesting_schedule.exit(&crate::ID)?;
+            }
+            summaries.push(ClaimSummary {
+                vesting_schedule: vesting_schedule.key(),
+                claimed_amount: claim_amount,
+                transfer_fee: 0,
+            });
+            continue;
+        }
+
+        let (Some(vault_info), Some(mint_info), Some(destination_info), Some(token_program_info)) = (
+            remaining_accounts.next(),
+            remaining_accounts.next(),
+            remaining_accounts.next(),
+            remaining_accounts.next(),
+        ) else {
+            return Err(ErrorCode::TokenAccountsRequired.into());
+        };
+        if claim_amount == 0 {
+            summaries.push(ClaimSummary {
+                vesting_schedule: vesting_schedule.key(),
+                claimed_amount: 0,
+                transfer_fee: 0,
+            });
+            continue;
+        }
+
+        let token_program = Interface::<TokenInterface>::try_from(token_program_info)?;
+        let token_mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        if token_mint.key() != vesting_schedule.token_mint || *mint_info.owner != token_program.key() {
+            return Err(ErrorCode::TokenAccountsRequired.into());
+        }
+        let vault_address = get_associated_token_address_with_program_id(
+            &vesting_schedule.key(),
+            &vesting_schedule.token_mint,
+            &token_program.key(),
+        );
+        let vesting_token_account = InterfaceAccount::<TokenAccount>::try_from(vault_info)?;
+        if vesting_token_account.key() != vault_address {
+            return Err(ErrorCode::TokenAccountsRequired.into());
+        }
+        let destination_token_account = InterfaceAccount::<TokenAccount>::try_from(destination_info)?;
+        if destination_token_account.mint != vesting_schedule.token_mint {
+            return Err(ErrorCode::InvalidDestinationTokenAccount.into());
+        }
+
+        let bump = VestingScheduleSeeds::find(&vesting_schedule).bump();
+        let transfer_fee = pay_out_claim(
+            &mut vesting_schedule,
+            bump,
+            Some(&vesting_token_account),
+            Some(&token_mint),
+            Some(&token_program),
+            employee.to_account_info(),
+            Some(destination_info.clone()),
+            claim_amount,
+        )?;
+        vesting_schedule.exit(&crate::ID)?;
+
+        summaries.push(ClaimSummary {
+            vesting_schedule: vesting_schedule.key(),
+            claimed_amount: claim_amount,
+            transfer_fee,
+        });
+    }
+
+    msg!(
+        "Employee claimed from {} of {} vesting schedules",
+        summaries.iter().filter(|summary| summary.claimed_amount > 0).count(),
+        summaries.len()
+    );
+    Ok(summaries)
+}
+
+#[derive(Accounts)]
+pub struct ClaimManyCtx<'info> {
+    #[account(mut)]
+    pub employee: Signer<'info>,
+    // remaining_accounts: writable VestingSchedule groups, then UnlockTable for table-based schedules
+}
@@ -1,54 +1,40 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
-use crate::{errors::ErrorCode, states::VestingSchedule};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    errors::ErrorCode,
+    states::{UnlockTable, VestingSchedule},
+    utils::pay_out_claim,
+};
 
-pub fn claim_tokens(ctx: Context<ClaimTokensCtx>) -> Result<()> {
+pub fn claim_tokens(ctx: Context<ClaimTokensCtx>, amount: Option<u64>) -> Result<()> {
     let vesting_schedule = &mut ctx.accounts.vesting_schedule;
     let current_time = Clock::get()?.unix_timestamp;
 
-    if vesting_schedule.revoked {
-        return Err(ErrorCode::VestingScheduleRevoked.into());
-    }
-    if current_time < vesting_schedule.cliff_time {
-        return Err(ErrorCode::CliffTimeNotReached.into());
-    }
-
-    let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time);
-    if claimable_amount == 0 {
-        return Err(ErrorCode::NoTokensAvailableToClaim.into());
-    }
-
-    vesting_schedule.claimed_amount += claimable_amount;
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
 
-    let org_id = vesting_schedule.org_id;
-    let employee = vesting_schedule.employee;
-    let token_mint = vesting_schedule.token_mint;
-    let schedule_id = vesting_schedule.schedule_id;
+    let claim_amount = vesting_schedule.amount_to_claim(current_time, ctx.accounts.unlock_table.as_deref(), amount)?;
 
-    let org_id = org_id.to_le_bytes();
-    let schedule_id = schedule_id.to_le_bytes();
-    let seeds = &[
-        b"vesting_schedule",
-        org_id.as_ref(),
-        employee.as_ref(),
-        token_mint.as_ref(),
-        schedule_id.as_ref(),
-        &[ctx.bumps.vesting_schedule],
-    ];
-    let signer_seeds = &[&seeds[..]];
+    let transfer_fee = pay_out_claim(
+        vesting_schedule,
+        ctx.bumps.vesting_schedule,
+        ctx.accounts.vesting_token_account.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.employee.to_account_info(),
+        ctx.accounts.employee_token_account.as_ref().map(|account| account.to_account_info()),
+        claim_amount,
+    )?;
 
-    let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.vesting_token_account.to_account_info(),
-            to: ctx.accounts.employee_token_account.to_account_info(),
-            authority: vesting_schedule.to_account_info(),
-        },
-        signer_seeds,
-    );
-    transfer(transfer_ctx, claimable_amount)?;
-
-    msg!("Employee claimed {} tokens. Total claimed: {}", claimable_amount, vesting_schedule.claimed_amount);
+    if vesting_schedule.native {
+        msg!("Employee claimed {} lamports. Total claimed: {}", claim_amount, vesting_schedule.claimed_amount);
+    } else {
+        msg!(
+            "Employee claimed {} tokens ({} withheld as transfer fee). Total claimed: {}",
+            claim_amount,
+            transfer_fee,
+            vesting_schedule.claimed_amount
+        );
+    }
     Ok(())
 }
 
@@ -59,30 +45,40 @@ pub struct ClaimTokensCtx<'info> {
         seeds = [
             b"vesting_schedule",
             vesting_schedule.org_id.to_le_bytes().as_ref(),
-            vesting_schedule.employee.as_ref(),
             vesting_schedule.token_mint.as_ref(),
             vesting_schedule.schedule_id.to_le_bytes().as_ref()
         ],
         bump,
-        constraint = vesting_schedule.employee == employee.key() @ ErrorCode::UnauthorizedEmployee
+        constraint = vesting_schedule.is_claim_authority(&claimer.key()) @ ErrorCode::UnauthorizedClaimer
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
     
+    // Token accounts are omitted for native SOL schedules
     #[account(
         mut,
-        seeds = [b"vesting_token_account", vesting_schedule.key().as_ref()],
-        bump
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
     )]
-    pub vesting_token_account: Account<'info, TokenAccount>,
+    pub vesting_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
+    // Claims always pay out to the employee, even when made by the claim delegate
     #[account(
         mut,
         constraint = employee_token_account.mint == vesting_schedule.token_mint,
         constraint = employee_token_account.owner == employee.key()
     )]
-    pub employee_token_account: Account<'info, TokenAccount>,
+    pub employee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
-    #[account(mut)]
-    pub employee: Signer<'info>,
-    pub token_program: Program<'info, Token>,
-}
\ No newline at end of file
+    /// CHECK: The schedule's beneficiary, receives the lamports of native SOL schedules
+    #[account(mut, address = vesting_schedule.employee)]
+    pub employee: UncheckedAccount<'info>,
+    // The employee or their claim delegate
+    pub claimer: Signer<'info>,
+    #[account(address = vesting_schedule.token_mint, mint::token_program = token_program)]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
+}
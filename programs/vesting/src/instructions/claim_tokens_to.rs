@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create, AssociatedToken, Create},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::{
+    errors::ErrorCode,
+    states::{UnlockTable, VestingSchedule},
+    utils::pay_out_claim,
+};
+
+/// Same as `claim_tokens`, but pays out to a destination of the employee's choosing.
+pub fn claim_tokens_to(ctx: Context<ClaimTokensToCtx>, amount: Option<u64>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
+    let claim_amount = vesting_schedule.amount_to_claim(current_time, ctx.accounts.unlock_table.as_deref(), amount)?;
+
+    if !vesting_schedule.native {
+        let (Some(destination_token_account), Some(token_mint), Some(token_program)) = (
+            &ctx.accounts.destination_token_account,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(ErrorCode::TokenAccountsRequired.into());
+        };
+
+        // A missing destination is created as the destination wallet's associated token account
+        if destination_token_account.data_is_empty() {
+            let Some(associated_token_program) = &ctx.accounts.associated_token_program else {
+                return Err(ErrorCode::TokenAccountsRequired.into());
+            };
+            let create_ctx = CpiContext::new(
+                associated_token_program.to_account_info(),
+                Create {
+                    payer: ctx.accounts.employee.to_account_info(),
+                    associated_token: destination_token_account.to_account_info(),
+                    authority: ctx.accounts.destination.to_account_info(),
+                    mint: token_mint.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                },
+            );
+            create(create_ctx)?;
+        }
+        if *destination_token_account.owner != token_program.key() {
+            return Err(ErrorCode::InvalidDestinationTokenAccount.into());
+        }
+        let destination_mint = TokenAccount::try_deserialize(&mut &destination_token_account.try_borrow_data()?[..])?.mint;
+        if destination_mint != vesting_schedule.token_mint {
+            return Err(ErrorCode::InvalidDestinationTokenAccount.into());
+        }
+    }
+
+    let transfer_fee = pay_out_claim(
+        vesting_schedule,
+        ctx.bumps.vesting_schedule,
+        ctx.accounts.vesting_token_account.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.destination.to_account_info(),
+        ctx.accounts.destination_token_account.as_ref().map(|account| account.to_account_info()),
+        claim_amount,
+    )?;
+
+    if vesting_schedule.native {
+        msg!(
+            "Employee claimed {} lamports to {}. Total claimed: {}",
+            claim_amount,
+            ctx.accounts.destination.key(),
+            vesting_schedule.claimed_amount
+        );
+    } else {
+        msg!(
+            "Employee claimed {} tokens to {} ({} withheld as transfer fee). Total claimed: {}",
+            claim_amount,
+            ctx.accounts.destination.key(),
+            transfer_fee,
+            vesting_schedule.claimed_amount
+        );
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimTokensToCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = vesting_schedule.employee == employee.key() @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Token accounts are omitted for native SOL schedules
+    #[account(
+        mut,
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Any wallet chosen by the employee; receives the lamports of native SOL schedules
+    /// and owns the destination token account if it has to be created
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: Any token account of the schedule's mint, validated in the handler; if it does not
+    /// exist yet it must be the associated token account of `destination`
+    #[account(mut)]
+    pub destination_token_account: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub employee: Signer<'info>,
+    #[account(address = vesting_schedule.token_mint, mint::token_program = token_program)]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    // Only needed when the destination token account has to be created
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, close_account};
+use crate::{
+    errors::ErrorCode,
+    states::{Employee, Organization, VestingSchedule},
+    utils::{harvest_vault_transfer_fees, transfer_from_vesting_vault, VestingScheduleSeeds},
+};
+
+pub fn close_vesting_schedule(ctx: Context<CloseVestingScheduleCtx>) -> Result<()> {
+    let vesting_schedule = &ctx.accounts.vesting_schedule;
+
+    // Completion comes from the accounting, so stray deposits cannot keep a schedule open
+    let owed = if vesting_schedule.revoked {
+        vesting_schedule.vested_at_revoke
+    } else {
+        vesting_schedule.total_amount
+    };
+    if vesting_schedule.claimed_amount < owed {
+        return Err(ErrorCode::VestingScheduleNotCompleted.into());
+    }
+
+    // Leftover lamports of native schedules go to the owner along with the rent through `close`
+    if !vesting_schedule.native {
+        let (Some(vesting_token_account), Some(token_mint), Some(token_program)) = (
+            &ctx.accounts.vesting_token_account,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(ErrorCode::TokenAccountsRequired.into());
+        };
+
+        // Sweep leftover tokens so the vault can be closed
+        if vesting_token_account.amount > 0 {
+            let Some(employer_token_account) = &ctx.accounts.employer_token_account else {
+                return Err(ErrorCode::TokenAccountsRequired.into());
+            };
+            transfer_from_vesting_vault(
+                vesting_schedule,
+                ctx.bumps.vesting_schedule,
+                vesting_token_account,
+                token_mint,
+                employer_token_account.to_account_info(),
+                token_program,
+                vesting_token_account.amount,
+            )?;
+            msg!("Swept {} leftover tokens to the organization owner", vesting_token_account.amount);
+        }
+
+        harvest_vault_transfer_fees(vesting_token_account, token_mint, token_program)?;
+
+        let seeds = VestingScheduleSeeds::new(vesting_schedule, ctx.bumps.vesting_schedule);
+        let signer_seeds = seeds.signer_seeds();
+        let signer_seeds = &[&signer_seeds[..]];
+
+        let close_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: vesting_token_account.to_account_info(),
+                destination: ctx.accounts.employer.to_account_info(),
+                authority: vesting_schedule.to_account_info(),
+            },
+            signer_seeds,
+        );
+        close_account(close_ctx)?;
+    }
+
+    // The global schedule counter doubles as the schedule id sequence, so only live counts shrink
+    let organization = &mut ctx.accounts.organization;
+    let employee = &mut ctx.accounts.employee;
+    organization.total_vesting_schedules -= 1;
+    employee.total_vesting_schedules -= 1;
+
+    msg!("Vesting schedule {} closed", ctx.accounts.vesting_schedule.schedule_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVestingScheduleCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        close = employer
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Token accounts are omitted for native SOL schedules
+    #[account(
+        mut,
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Only when the vault still holds leftover tokens
+    #[account(
+        mut,
+        constraint = employer_token_account.mint == vesting_schedule.token_mint,
+        constraint = employer_token_account.owner == employer.key()
+    )]
+    pub employer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", vesting_schedule.org_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = organization.owner == employer.key() @ ErrorCode::UnauthorizedOrganizationOwner
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(mut, address = vesting_schedule.employee_record)]
+    pub employee: Account<'info, Employee>,
+
+    #[account(mut)]
+    pub employer: Signer<'info>,
+    // Writable so withheld transfer fees can be harvested into it
+    #[account(mut, address = vesting_schedule.token_mint, mint::token_program = token_program)]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction},
+};
+
+/// Sets the tip paid to `crank_distribute` callers and tops up the organization account that pays it.
+pub fn configure_crank_tips(
+    ctx: Context<ConfigureCrankTipsCtx>,
+    _org_id: u64,
+    tip_lamports: u64,
+    deposit_lamports: u64,
+) -> Result<()> {
+    if tip_lamports > MAX_CRANK_TIP_LAMPORTS {
+        return Err(ErrorCode::CrankTipTooLarge.into());
+    }
+
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::ConfigureCrankTips { tip_lamports, deposit_lamports },
+    )?;
+
+    if deposit_lamports > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.organization.to_account_info(),
+            },
+        );
+        system_program::transfer(transfer_ctx, deposit_lamports)?;
+    }
+
+    let organization = &mut ctx.accounts.organization;
+    organization.crank_tip_lamports = tip_lamports;
+
+    msg!(
+        "Crank tip for organization '{}' set to {} lamports ({} lamports deposited)",
+        organization.name,
+        tip_lamports,
+        deposit_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct ConfigureCrankTipsCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // The owner, or a multisig signer executing the proposal; pays the deposit
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
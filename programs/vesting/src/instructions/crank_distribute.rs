@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, UnlockTable, VestingSchedule},
+    utils::{pay_out_claim, transfer_lamports},
+};
+
+/// Permissionless: pushes everything claimable to the employee of an auto-distribute schedule.
+pub fn crank_distribute(ctx: Context<CrankDistributeCtx>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if !vesting_schedule.auto_distribute {
+        return Err(ErrorCode::AutoDistributeDisabled.into());
+    }
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
+    let claim_amount = vesting_schedule.amount_to_claim(current_time, ctx.accounts.unlock_table.as_deref(), None)?;
+
+    let transfer_fee = pay_out_claim(
+        vesting_schedule,
+        ctx.bumps.vesting_schedule,
+        ctx.accounts.vesting_token_account.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.employee.to_account_info(),
+        ctx.accounts.employee_token_account.as_ref().map(|account| account.to_account_info()),
+        claim_amount,
+    )?;
+
+    // The tip is best effort: an unfunded organization still gets its schedules distributed.
+    // Paying at most once per interval keeps frequent cranks of per-second schedules from draining the pool.
+    let organization = &ctx.accounts.organization;
+    let organization_info = organization.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ANCHOR_DISCRIMINATOR_SIZE + Organization::INIT_SPACE);
+    let tip = organization.crank_tip_lamports;
+    let tip_paid = tip > 0
+        && current_time - vesting_schedule.last_crank_tip_time >= MIN_CRANK_TIP_INTERVAL
+        && organization_info.lamports().saturating_sub(rent_exempt_minimum) >= tip;
+    if tip_paid {
+        transfer_lamports(&organization_info, &ctx.accounts.cranker.to_account_info(), tip)?;
+        vesting_schedule.last_crank_tip_time = current_time;
+    }
+
+    msg!(
+        "Distributed {} to employee of vesting schedule {} ({} withheld as transfer fee). Total claimed: {}. Tip paid: {}",
+        claim_amount,
+        vesting_schedule.schedule_id,
+        transfer_fee,
+        vesting_schedule.claimed_amount,
+        if tip_paid { tip } else { 0 }
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrankDistributeCtx<'info> {
+    #[account(
+        mut,
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Token accounts are omitted for native SOL schedules
+    #[account(
+        mut,
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Tokens always go to the employee's associated token account
+    #[account(
+        mut,
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = employee,
+        associated_token::token_program = token_program,
+    )]
+    pub employee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: The schedule's beneficiary, receives the lamports of native SOL schedules
+    #[account(mut, address = vesting_schedule.employee)]
+    pub employee: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    #[account(address = vesting_schedule.token_mint, mint::token_program = token_program)]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Invite, OrgRole, Organization, Permission},
+};
+
+/// Invites a wallet, or the public key of an invite code keypair shared off-chain, to join the organization.
+/// An expired invite for the same invitee is replaced, refunding its rent to its creator.
+pub fn create_invite(
+    ctx: Context<CreateInviteCtx>,
+    _org_id: u64,
+    invitee: Pubkey,
+    is_code: bool,
+    expires_at: i64,
+) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let invite = &mut ctx.accounts.invite;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    organization.authorize_member(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::ManageEmployees,
+    )?;
+    if expires_at <= current_time {
+        return Err(ErrorCode::InviteExpired.into());
+    }
+
+    // An existing invite has a creator; it may only be replaced once expired
+    if invite.created_by != Pubkey::default() {
+        if current_time < invite.expires_at {
+            return Err(ErrorCode::InviteStillActive.into());
+        }
+        let Some(previous_invite_creator) = &ctx.accounts.previous_invite_creator else {
+            return Err(ErrorCode::InvalidInvite.into());
+        };
+        if previous_invite_creator.key() != invite.created_by {
+            return Err(ErrorCode::InvalidInvite.into());
+        }
+        // The new creator takes over the rent, and with it the refund on join or cancel
+        if previous_invite_creator.key() != ctx.accounts.authority.key() {
+            let rent = invite.to_account_info().lamports();
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: previous_invite_creator.to_account_info(),
+                },
+            );
+            system_program::transfer(transfer_ctx, rent)?;
+        }
+    }
+
+    invite.org_id = organization.org_id;
+    invite.invitee = invitee;
+    invite.is_code = is_code;
+    invite.created_by = ctx.accounts.authority.key();
+    invite.created_at = current_time;
+    invite.expires_at = expires_at;
+
+    msg!(
+        "Invite to organization '{}' created for {}, expiring at {}",
+        organization.name,
+        invitee,
+        expires_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64, invitee: Pubkey)]
+pub struct CreateInviteCtx<'info> {
+    #[account(
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Invite::INIT_SPACE,
+        seeds = [b"invite", org_id.to_le_bytes().as_ref(), invitee.as_ref()],
+        bump
+    )]
+    pub invite: Account<'info, Invite>,
+
+    // The owner, or an HR or Admin member
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+
+    /// CHECK: Creator of the expired invite being replaced, refunded its rent; checked against `invite.created_by`
+    #[account(mut)]
+    pub previous_invite_creator: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
@@ -16,8 +16,8 @@ pub fn create_organization(
     let program_state = &mut ctx.accounts.program_state;
     let organization = &mut ctx.accounts.organization;
     
-    organization.org_id = program_state.total_organizations;
     program_state.total_organizations += 1;
+    organization.org_id = program_state.total_organizations;
     
     organization.name = name.clone();
     organization.owner = ctx.accounts.owner.key();
@@ -25,6 +25,15 @@ pub fn create_organization(
     organization.total_vesting_schedules = 0;
     organization.created_at = Clock::get()?.unix_timestamp;
     organization.active = true;
+    organization.total_unlock_tables = 0;
+    organization.milestone_attester = ctx.accounts.owner.key();
+    organization.beneficiary_rotation_requires_approval = false;
+    organization.crank_tip_lamports = 0;
+    organization.multisig_signers = Vec::new();
+    organization.multisig_threshold = 0;
+    organization.total_proposals = 0;
+    organization.pending_owner = None;
+    organization.open_enrollment = false;
 
     msg!("Organization '{}' created with ID: {}", name, organization.org_id);
     Ok(())
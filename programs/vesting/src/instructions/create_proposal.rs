@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction},
+};
+
+pub fn create_proposal(
+    ctx: Context<CreateProposalCtx>,
+    _org_id: u64,
+    action: ProposalAction,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = ctx.accounts.proposer.key();
+
+    if !organization.is_multisig() {
+        return Err(ErrorCode::MultisigNotEnabled.into());
+    }
+    if !organization.is_multisig_signer(&proposer) {
+        return Err(ErrorCode::NotMultisigSigner.into());
+    }
+
+    organization.total_proposals += 1;
+
+    proposal.org_id = organization.org_id;
+    proposal.proposal_id = organization.total_proposals;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    // Proposing counts as the proposer's approval
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Proposal {} created for organization '{}'", proposal.proposal_id, organization.name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct CreateProposalCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Proposal::INIT_SPACE,
+        seeds = [
+            b"proposal",
+            org_id.to_le_bytes().as_ref(),
+            (organization.total_proposals + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction, UnlockCheckpoint, UnlockTable},
+    utils::hash_unlock_checkpoints,
+};
+
+pub fn create_unlock_table(
+    ctx: Context<CreateUnlockTableCtx>,
+    _org_id: u64,
+    checkpoints: Vec<UnlockCheckpoint>,
+    interpolate: bool,
+) -> Result<()> {
+    if checkpoints.is_empty() || checkpoints.len() > MAX_UNLOCK_CHECKPOINTS {
+        return Err(ErrorCode::InvalidUnlockTable.into());
+    }
+    for pair in checkpoints.windows(2) {
+        if pair[0].timestamp >= pair[1].timestamp || pair[0].cumulative_amount > pair[1].cumulative_amount {
+            return Err(ErrorCode::InvalidUnlockTable.into());
+        }
+    }
+    if checkpoints[checkpoints.len() - 1].cumulative_amount == 0 {
+        return Err(ErrorCode::InvalidUnlockTable.into());
+    }
+
+    let organization = &mut ctx.accounts.organization;
+    let unlock_table = &mut ctx.accounts.unlock_table;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::CreateUnlockTable { checkpoints_hash: hash_unlock_checkpoints(&checkpoints), interpolate },
+    )?;
+
+    organization.total_unlock_tables += 1;
+
+    unlock_table.org_id = organization.org_id;
+    unlock_table.table_id = organization.total_unlock_tables;
+    unlock_table.creator = ctx.accounts.owner.key();
+    unlock_table.interpolate = interpolate;
+    unlock_table.checkpoints = checkpoints;
+    unlock_table.created_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Unlock table {} created for organization '{}' with {} checkpoints",
+        unlock_table.table_id,
+        organization.name,
+        unlock_table.checkpoints.len()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct CreateUnlockTableCtx<'info> {
+    #[account(mut, seeds = [b"organization", org_id.to_le_bytes().as_ref()], bump)]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UnlockTable::INIT_SPACE,
+        seeds = [
+            b"unlock_table",
+            org_id.to_le_bytes().as_ref(),
+            (organization.total_unlock_tables + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub unlock_table: Account<'info, UnlockTable>,
+
+    // The owner, or a multisig signer executing the proposal
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
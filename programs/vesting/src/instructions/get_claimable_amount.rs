@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
-use crate::states::VestingSchedule;
+use crate::states::{UnlockTable, VestingSchedule};
 
 pub fn get_claimable_amount(ctx: Context<GetClaimableAmountCtx>) -> Result<u64> {
     let vesting_schedule = &ctx.accounts.vesting_schedule;
     let current_time = Clock::get()?.unix_timestamp;
-    
-    Ok(vesting_schedule.calculate_claimable_amount(current_time))
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
+
+    Ok(vesting_schedule.calculate_claimable_amount(current_time, ctx.accounts.unlock_table.as_deref()))
 }
 
 #[derive(Accounts)]
@@ -14,11 +15,13 @@ pub struct GetClaimableAmountCtx<'info> {
         seeds = [
             b"vesting_schedule",
             vesting_schedule.org_id.to_le_bytes().as_ref(),
-            vesting_schedule.employee.as_ref(),
             vesting_schedule.token_mint.as_ref(),
             vesting_schedule.schedule_id.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
 }
\ No newline at end of file
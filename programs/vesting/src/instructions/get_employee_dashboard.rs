@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::{VestingInfo, states::{Employee, VestingSchedule}};
+use crate::{
+    VestingInfo,
+    states::{Employee, VestingSchedule},
+    utils::{find_unlock_table, load_unlock_tables},
+};
 
 pub fn get_employee_dashboard<'info>(
     ctx: Context<'_, '_, 'info, 'info, GetEmployeeDashboardCtx<'info>>,
@@ -8,6 +12,9 @@ pub fn get_employee_dashboard<'info>(
     let employee_key = ctx.accounts.employee.key();
     let mut vesting_schedules = Vec::new();
 
+    // Unlock tables of table-based schedules are appended after the schedule pairs
+    let unlock_tables = load_unlock_tables(ctx.remaining_accounts);
+
     for chunk in ctx.remaining_accounts.chunks(2) {
         let sched_info = &chunk[0];
 
@@ -27,8 +34,12 @@ pub fn get_employee_dashboard<'info>(
                 ("Unknown".to_string(), "Unknown".to_string())
             };
 
-            let vested_amount = vesting_schedule.calculate_vested_amount(current_time);
-            let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time);
+            let Ok(unlock_table) = find_unlock_table(&vesting_schedule, &unlock_tables) else {
+                continue;
+            };
+
+            let vested_amount = vesting_schedule.calculate_vested_amount(current_time, unlock_table);
+            let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time, unlock_table);
 
             vesting_schedules.push(VestingInfo {
                 employer: vesting_schedule.employer,
@@ -37,7 +48,10 @@ pub fn get_employee_dashboard<'info>(
                 total_amount: vesting_schedule.total_amount,
                 start_time: vesting_schedule.start_time,
                 cliff_time: vesting_schedule.cliff_time,
-                end_time: vesting_schedule.end_time,
+                end_time: vesting_schedule.extended_end_time(current_time),
+                period_seconds: vesting_schedule.period_seconds,
+                cliff_unlock_amount: vesting_schedule.cliff_unlock_amount,
+                initial_unlock_amount: vesting_schedule.initial_unlock_amount,
                 claimed_amount: vesting_schedule.claimed_amount,
                 vested_amount,
                 claimable_amount,
@@ -47,6 +61,21 @@ pub fn get_employee_dashboard<'info>(
                 employee_position,
                 created_at: vesting_schedule.created_at,
                 org_id: vesting_schedule.org_id,
+                unlock_table: vesting_schedule.unlock_table,
+                milestones: vesting_schedule.milestones.clone(),
+                native: vesting_schedule.native,
+                top_up_amount: vesting_schedule.top_up_amount,
+                top_up_start_time: vesting_schedule.top_up_start_time,
+                amendment_count: vesting_schedule.amendment_count,
+                paused_at: vesting_schedule.paused_at,
+                pause_intervals: vesting_schedule.pause_intervals.clone(),
+                accelerated_amount: vesting_schedule.accelerated_amount,
+                termination_policy: vesting_schedule.termination_policy.clone(),
+                termination_mode: vesting_schedule.termination_mode,
+                auto_distribute: vesting_schedule.auto_distribute,
+                claim_delegate: vesting_schedule.claim_delegate,
+                transfer_fees_withheld: vesting_schedule.transfer_fees_withheld,
+                last_crank_tip_time: vesting_schedule.last_crank_tip_time,
             });
         }
     }
@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::{VestingInfo, states::{Organization, VestingSchedule, Employee}, errors::ErrorCode};
+use crate::{
+    VestingInfo,
+    states::{OrgRole, Organization, VestingSchedule, Employee},
+    utils::{find_unlock_table, load_unlock_tables},
+};
 
 pub fn get_employer_dashboard<'info>(
     ctx: Context<'_, '_ , 'info, 'info, GetEmployerDashboardCtx<'info>>,
@@ -7,13 +11,14 @@ pub fn get_employer_dashboard<'info>(
 ) -> Result<Vec<VestingInfo>> {
     let organization = &ctx.accounts.organization;
     
-    if organization.owner != ctx.accounts.employer.key() {
-        return Err(ErrorCode::UnauthorizedOrganizationOwner.into());
-    }
+    organization.authorize_viewer(&ctx.accounts.employer.key(), ctx.accounts.role.as_deref())?;
 
     let current_time = Clock::get()?.unix_timestamp;
     let mut vesting_schedules = Vec::new();
 
+    // Unlock tables of table-based schedules are appended after the schedule pairs
+    let unlock_tables = load_unlock_tables(ctx.remaining_accounts);
+
     // Expect remaining_accounts in pairs: [VestingSchedule, Employee, VestingSchedule, Employee, ...]
     for chunk in ctx.remaining_accounts.chunks(2) {
         let sched_info = &chunk[0];
@@ -34,8 +39,12 @@ pub fn get_employer_dashboard<'info>(
                 ("Unknown".to_string(), "Unknown".to_string())
             };
 
-            let vested_amount = vesting_schedule.calculate_vested_amount(current_time);
-            let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time);
+            let Ok(unlock_table) = find_unlock_table(&vesting_schedule, &unlock_tables) else {
+                continue;
+            };
+
+            let vested_amount = vesting_schedule.calculate_vested_amount(current_time, unlock_table);
+            let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time, unlock_table);
 
             vesting_schedules.push(VestingInfo {
                 employer: vesting_schedule.employer,
@@ -44,7 +53,10 @@ pub fn get_employer_dashboard<'info>(
                 total_amount: vesting_schedule.total_amount,
                 start_time: vesting_schedule.start_time,
                 cliff_time: vesting_schedule.cliff_time,
-                end_time: vesting_schedule.end_time,
+                end_time: vesting_schedule.extended_end_time(current_time),
+                period_seconds: vesting_schedule.period_seconds,
+                cliff_unlock_amount: vesting_schedule.cliff_unlock_amount,
+                initial_unlock_amount: vesting_schedule.initial_unlock_amount,
                 claimed_amount: vesting_schedule.claimed_amount,
                 vested_amount,
                 claimable_amount,
@@ -54,6 +66,21 @@ pub fn get_employer_dashboard<'info>(
                 employee_position,
                 created_at: vesting_schedule.created_at,
                 org_id: vesting_schedule.org_id,
+                unlock_table: vesting_schedule.unlock_table,
+                milestones: vesting_schedule.milestones.clone(),
+                native: vesting_schedule.native,
+                top_up_amount: vesting_schedule.top_up_amount,
+                top_up_start_time: vesting_schedule.top_up_start_time,
+                amendment_count: vesting_schedule.amendment_count,
+                paused_at: vesting_schedule.paused_at,
+                pause_intervals: vesting_schedule.pause_intervals.clone(),
+                accelerated_amount: vesting_schedule.accelerated_amount,
+                termination_policy: vesting_schedule.termination_policy.clone(),
+                termination_mode: vesting_schedule.termination_mode,
+                auto_distribute: vesting_schedule.auto_distribute,
+                claim_delegate: vesting_schedule.claim_delegate,
+                transfer_fees_withheld: vesting_schedule.transfer_fees_withheld,
+                last_crank_tip_time: vesting_schedule.last_crank_tip_time,
             });
         }
     }
@@ -70,5 +97,7 @@ pub struct GetEmployerDashboardCtx<'info> {
     )]
     pub organization: Account<'info, Organization>,
     pub employer: Signer<'info>,
-    // remaining_accounts: VestingSchedule, Employee, ...
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+    // remaining_accounts: VestingSchedule, Employee, ..., then UnlockTable for table-based schedules
 }
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{EmployeeInfo, states::{Organization, Employee}, errors::ErrorCode};
+use crate::{EmployeeInfo, states::{OrgRole, Organization, Employee}};
 
 pub fn get_organization_employees<'info>(
     ctx: Context<'_, '_ , 'info, 'info, GetOrganizationEmployeesCtx<'info>>,
@@ -7,9 +7,7 @@ pub fn get_organization_employees<'info>(
 ) -> Result<Vec<EmployeeInfo>> {
     let organization = &ctx.accounts.organization;
     
-    if organization.owner != ctx.accounts.owner.key() {
-        return Err(ErrorCode::UnauthorizedOrganizationOwner.into());
-    }
+    organization.authorize_viewer(&ctx.accounts.owner.key(), ctx.accounts.role.as_deref())?;
 
     let mut employees = Vec::new();
 
@@ -41,4 +39,6 @@ pub struct GetOrganizationEmployeesCtx<'info> {
     )]
     pub organization: Account<'info, Organization>,
     pub owner: Signer<'info>,
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
 }
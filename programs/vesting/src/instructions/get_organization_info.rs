@@ -11,10 +11,17 @@ pub fn get_organization_info(
         org_id: organization.org_id,
         name: organization.name.clone(),
         owner: organization.owner,
+        pending_owner: organization.pending_owner,
         total_employees: organization.total_employees,
         total_vesting_schedules: organization.total_vesting_schedules,
         created_at: organization.created_at,
         active: organization.active,
+        milestone_attester: organization.milestone_attester,
+        beneficiary_rotation_requires_approval: organization.beneficiary_rotation_requires_approval,
+        crank_tip_lamports: organization.crank_tip_lamports,
+        multisig_signers: organization.multisig_signers.clone(),
+        multisig_threshold: organization.multisig_threshold,
+        open_enrollment: organization.open_enrollment,
     })
 }
 
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::{states::VestingSchedule, VestingInfo};
+use crate::{states::{UnlockTable, VestingSchedule}, VestingInfo};
 
 pub fn get_vesting_info(ctx: Context<GetVestingInfoCtx>) -> Result<VestingInfo> {
     let vesting_schedule = &ctx.accounts.vesting_schedule;
     let current_time = Clock::get()?.unix_timestamp;
-    
-    let vested_amount = vesting_schedule.calculate_vested_amount(current_time);
-    let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time);
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
+    let unlock_table = ctx.accounts.unlock_table.as_deref();
+
+    let vested_amount = vesting_schedule.calculate_vested_amount(current_time, unlock_table);
+    let claimable_amount = vesting_schedule.calculate_claimable_amount(current_time, unlock_table);
 
     Ok(VestingInfo {
         employer: vesting_schedule.employer,
@@ -15,7 +17,10 @@ pub fn get_vesting_info(ctx: Context<GetVestingInfoCtx>) -> Result<VestingInfo>
         total_amount: vesting_schedule.total_amount,
         start_time: vesting_schedule.start_time,
         cliff_time: vesting_schedule.cliff_time,
-        end_time: vesting_schedule.end_time,
+        end_time: vesting_schedule.extended_end_time(current_time),
+        period_seconds: vesting_schedule.period_seconds,
+        cliff_unlock_amount: vesting_schedule.cliff_unlock_amount,
+        initial_unlock_amount: vesting_schedule.initial_unlock_amount,
         claimed_amount: vesting_schedule.claimed_amount,
         vested_amount,
         claimable_amount,
@@ -25,6 +30,21 @@ pub fn get_vesting_info(ctx: Context<GetVestingInfoCtx>) -> Result<VestingInfo>
         employee_position: String::new(), // Would need to fetch from Employee account
         created_at: vesting_schedule.created_at,
         org_id: vesting_schedule.org_id,
+        unlock_table: vesting_schedule.unlock_table,
+        milestones: vesting_schedule.milestones.clone(),
+        native: vesting_schedule.native,
+        top_up_amount: vesting_schedule.top_up_amount,
+        top_up_start_time: vesting_schedule.top_up_start_time,
+        amendment_count: vesting_schedule.amendment_count,
+        paused_at: vesting_schedule.paused_at,
+        pause_intervals: vesting_schedule.pause_intervals.clone(),
+        accelerated_amount: vesting_schedule.accelerated_amount,
+        termination_policy: vesting_schedule.termination_policy.clone(),
+        termination_mode: vesting_schedule.termination_mode,
+        auto_distribute: vesting_schedule.auto_distribute,
+        claim_delegate: vesting_schedule.claim_delegate,
+        transfer_fees_withheld: vesting_schedule.transfer_fees_withheld,
+        last_crank_tip_time: vesting_schedule.last_crank_tip_time,
     })
 }
 
@@ -34,11 +54,13 @@ pub struct GetVestingInfoCtx<'info> {
         seeds = [
             b"vesting_schedule",
             vesting_schedule.org_id.to_le_bytes().as_ref(),
-            vesting_schedule.employee.as_ref(),
             vesting_schedule.token_mint.as_ref(),
             vesting_schedule.schedule_id.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
 }
\ No newline at end of file
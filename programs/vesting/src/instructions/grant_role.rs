@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    states::{OrgRole, Organization, Proposal, ProposalAction, Role},
+};
+
+pub fn grant_role(
+    ctx: Context<GrantRoleCtx>,
+    _org_id: u64,
+    member: Pubkey,
+    role: Role,
+) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let org_role = &mut ctx.accounts.org_role;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::GrantRole { member, role },
+    )?;
+
+    org_role.org_id = organization.org_id;
+    org_role.member = member;
+    org_role.role = role;
+    org_role.granted_by = ctx.accounts.owner.key();
+    org_role.granted_at = Clock::get()?.unix_timestamp;
+
+    msg!("Granted {:?} role in organization '{}' to {}", role, organization.name, member);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64, member: Pubkey)]
+pub struct GrantRoleCtx<'info> {
+    #[account(
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + OrgRole::INIT_SPACE,
+        seeds = [b"org_role", org_id.to_le_bytes().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub org_role: Account<'info, OrgRole>,
+
+    // The owner, or a multisig signer executing the proposal
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
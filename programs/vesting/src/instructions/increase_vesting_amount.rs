@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{
+    errors::ErrorCode,
+    states::{OrgRole, Organization, Permission, Proposal, ProposalAction, VestingSchedule},
+    utils::calculate_inverse_transfer_fee,
+};
+
+pub fn increase_vesting_amount(ctx: Context<IncreaseVestingAmountCtx>, amount: u64) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if amount == 0 {
+        return Err(ErrorCode::InvalidTotalAmount.into());
+    }
+    ctx.accounts.organization.authorize_role_action(
+        &ctx.accounts.employer.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::FundGrants,
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::IncreaseVestingAmount { vesting_schedule: vesting_schedule.key(), amount },
+    )?;
+    if vesting_schedule.revoked {
+        return Err(ErrorCode::VestingScheduleRevoked.into());
+    }
+    // Table and milestone schedules have fixed tranches the extra amount cannot be spread over
+    if vesting_schedule.unlock_table.is_some() || vesting_schedule.is_milestone_based() {
+        return Err(ErrorCode::TopUpNotSupported.into());
+    }
+
+    vesting_schedule.add_top_up(amount, current_time)?;
+
+    if vesting_schedule.native {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.employer.to_account_info(),
+                to: vesting_schedule.to_account_info(),
+            },
+        );
+        system_program::transfer(transfer_ctx, amount)?;
+    } else {
+        let (Some(vesting_token_account), Some(employer_token_account), Some(token_mint), Some(token_program)) = (
+            &mut ctx.accounts.vesting_token_account,
+            &ctx.accounts.employer_token_account,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(ErrorCode::TokenAccountsRequired.into());
+        };
+
+        // Gross up transfer-fee mints so the vault receives exactly the added amount
+        let balance_before = vesting_token_account.amount;
+        let transfer_fee = calculate_inverse_transfer_fee(&token_mint.to_account_info(), amount)?;
+        let transfer_amount = amount
+            .checked_add(transfer_fee)
+            .ok_or(ErrorCode::TransferFeeCalculationFailed)?;
+
+        let transfer_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: employer_token_account.to_account_info(),
+                mint: token_mint.to_account_info(),
+                to: vesting_token_account.to_account_info(),
+                authority: ctx.accounts.employer.to_account_info(),
+            },
+        );
+        transfer_checked(transfer_ctx, transfer_amount, token_mint.decimals)?;
+
+        vesting_token_account.reload()?;
+        if vesting_token_account.amount < balance_before + amount {
+            return Err(ErrorCode::InsufficientTokensInVestingAccount.into());
+        }
+    }
+
+    msg!(
+        "Vesting schedule {} topped up by {}, vesting until {}. New total: {}",
+        vesting_schedule.schedule_id,
+        amount,
+        vesting_schedule.end_time,
+        vesting_schedule.total_amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IncreaseVestingAmountCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // Token accounts are omitted for native SOL schedules
+    #[account(
+        mut,
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = employer_token_account.mint == vesting_schedule.token_mint,
+        constraint = employer_token_account.owner == employer.key()
+    )]
+    pub employer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // The owner, a Finance or Admin member, or a multisig signer executing the proposal
+    #[account(mut)]
+    pub employer: Signer<'info>,
+    #[account(address = vesting_schedule.token_mint, mint::token_program = token_program)]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+use anchor_spl::token::spl_token::native_mint;
+use crate::{
+    constants::*,
+    states::{Employee, OrgRole, Organization, Permission, ProgramState, Proposal, ProposalAction, TerminationPolicy, UnlockTable, VestingSchedule, VestingScheduleTerms},
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_native_vesting_schedule(
+    ctx: Context<InitializeNativeVestingScheduleCtx>,
+    _org_id: u64,
+    total_amount: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+    period_seconds: i64,
+    cliff_unlock_amount: u64,
+    initial_unlock_amount: u64,
+    milestone_amounts: Vec<u64>,
+    revocable: bool,
+    termination_policy: TerminationPolicy,
+) -> Result<()> {
+    let terms = VestingScheduleTerms {
+        total_amount,
+        start_time,
+        cliff_time,
+        end_time,
+        period_seconds,
+        cliff_unlock_amount,
+        initial_unlock_amount,
+        milestone_amounts,
+        revocable,
+        termination_policy,
+    };
+    let action = ProposalAction::InitializeVestingSchedule {
+        employee: ctx.accounts.employee.employee,
+        token_mint: native_mint::ID,
+        unlock_table: ctx.accounts.unlock_table.as_ref().map(|unlock_table| unlock_table.key()),
+        terms: terms.clone(),
+    };
+    ctx.accounts.organization.authorize_role_action(
+        &ctx.accounts.employer.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::FundGrants,
+        ctx.accounts.proposal.as_deref_mut(),
+        &action,
+    )?;
+
+    let organization = &mut ctx.accounts.organization;
+    let employee = &mut ctx.accounts.employee;
+    let program_state = &mut ctx.accounts.program_state;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    program_state.total_vesting_schedules += 1;
+    organization.total_vesting_schedules += 1;
+    employee.total_vesting_schedules += 1;
+
+    vesting_schedule.init(
+        program_state.total_vesting_schedules,
+        ctx.accounts.employer.key(),
+        employee,
+        native_mint::ID,
+        ctx.accounts.unlock_table.as_ref(),
+        true,
+        terms,
+    )?;
+
+    // The lamports vest straight out of the schedule PDA, on top of its rent-exempt reserve
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.employer.to_account_info(),
+            to: vesting_schedule.to_account_info(),
+        },
+    );
+    transfer(transfer_ctx, total_amount)?;
+
+    msg!(
+        "Native SOL vesting schedule created for employee: {} in organization: {}, lamports: {}",
+        employee.name,
+        organization.name,
+        total_amount
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct InitializeNativeVestingScheduleCtx<'info> {
+    #[account(mut)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [b"organization", org_id.to_le_bytes().as_ref()], bump)]
+    pub organization: Account<'info, Organization>,
+
+    #[account(mut, seeds = [b"employee", employee.employee.as_ref(), org_id.to_le_bytes().as_ref()], bump)]
+    pub employee: Account<'info, Employee>,
+
+    #[account(init, payer = employer, space = ANCHOR_DISCRIMINATOR_SIZE + VestingSchedule::INIT_SPACE,
+        seeds = [
+            b"vesting_schedule",
+            org_id.to_le_bytes().as_ref(),
+            native_mint::ID.as_ref(),
+            (program_state.total_vesting_schedules + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // The owner, a Finance or Admin member, or a multisig signer executing the proposal
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
+
+    pub system_program: Program<'info, System>,
+}
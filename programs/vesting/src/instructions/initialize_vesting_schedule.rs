@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
 use crate::{
     constants::*,
     errors::ErrorCode,
-    states::{Employee, Organization, ProgramState, VestingSchedule}
+    states::{Employee, OrgRole, Organization, Permission, ProgramState, Proposal, ProposalAction, TerminationPolicy, UnlockTable, VestingSchedule, VestingScheduleTerms},
+    utils::calculate_inverse_transfer_fee,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_vesting_schedule(
     ctx: Context<InitializeVestingScheduleCtx>,
     _org_id: u64,
@@ -13,68 +15,91 @@ pub fn initialize_vesting_schedule(
     start_time: i64,
     cliff_time: i64,
     end_time: i64,
+    period_seconds: i64,
+    cliff_unlock_amount: u64,
+    initial_unlock_amount: u64,
+    milestone_amounts: Vec<u64>,
     revocable: bool,
+    termination_policy: TerminationPolicy,
 ) -> Result<()> {
-    if total_amount == 0 {
-        return Err(ErrorCode::InvalidTotalAmount.into());
-    }
-    if start_time >= cliff_time || cliff_time >= end_time {
-        return Err(ErrorCode::InvalidTimeParameters.into());
-    }
-    if end_time - start_time < MIN_VESTING_DURATION {
-        return Err(ErrorCode::VestingDurationTooShort.into());
-    }
+    let terms = VestingScheduleTerms {
+        total_amount,
+        start_time,
+        cliff_time,
+        end_time,
+        period_seconds,
+        cliff_unlock_amount,
+        initial_unlock_amount,
+        milestone_amounts,
+        revocable,
+        termination_policy,
+    };
+    let action = ProposalAction::InitializeVestingSchedule {
+        employee: ctx.accounts.employee.employee,
+        token_mint: ctx.accounts.token_mint.key(),
+        unlock_table: ctx.accounts.unlock_table.as_ref().map(|unlock_table| unlock_table.key()),
+        terms: terms.clone(),
+    };
+    ctx.accounts.organization.authorize_role_action(
+        &ctx.accounts.employer.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::FundGrants,
+        ctx.accounts.proposal.as_deref_mut(),
+        &action,
+    )?;
 
     let organization = &mut ctx.accounts.organization;
     let employee = &mut ctx.accounts.employee;
     let program_state = &mut ctx.accounts.program_state;
     let vesting_schedule = &mut ctx.accounts.vesting_schedule;
 
-    if organization.owner != ctx.accounts.employer.key() {
-        return Err(ErrorCode::UnauthorizedOrganizationOwner.into());
-    }
-    if !employee.active {
-        return Err(ErrorCode::EmployeeNotActive.into());
-    }
-
     program_state.total_vesting_schedules += 1;
     organization.total_vesting_schedules += 1;
     employee.total_vesting_schedules += 1;
-    
-    vesting_schedule.org_id = organization.org_id;
-    vesting_schedule.employer = ctx.accounts.employer.key();
-    vesting_schedule.employee = employee.employee;
-    vesting_schedule.token_mint = ctx.accounts.token_mint.key();
-    vesting_schedule.total_amount = total_amount;
-    vesting_schedule.start_time = start_time;
-    vesting_schedule.cliff_time = cliff_time;
-    vesting_schedule.end_time = end_time;
-    vesting_schedule.claimed_amount = 0;
-    vesting_schedule.revoked = false;
-    vesting_schedule.revocable = revocable;
-    vesting_schedule.revoke_time = None;
-    vesting_schedule.schedule_id = program_state.total_vesting_schedules;
-    vesting_schedule.created_at = Clock::get()?.unix_timestamp;
+
+    vesting_schedule.init(
+        program_state.total_vesting_schedules,
+        ctx.accounts.employer.key(),
+        employee,
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.unlock_table.as_ref(),
+        false,
+        terms,
+    )?;
+
+    // Gross up transfer-fee mints so the vault receives exactly total_amount
+    let transfer_fee = calculate_inverse_transfer_fee(&ctx.accounts.token_mint.to_account_info(), total_amount)?;
+    let transfer_amount = total_amount
+        .checked_add(transfer_fee)
+        .ok_or(ErrorCode::TransferFeeCalculationFailed)?;
 
     let transfer_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.employer_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
             to: ctx.accounts.vesting_token_account.to_account_info(),
             authority: ctx.accounts.employer.to_account_info(),
         },
     );
-    transfer(transfer_ctx, total_amount)?;
+    transfer_checked(transfer_ctx, transfer_amount, ctx.accounts.token_mint.decimals)?;
+
+    ctx.accounts.vesting_token_account.reload()?;
+    if ctx.accounts.vesting_token_account.amount < total_amount {
+        return Err(ErrorCode::InsufficientTokensInVestingAccount.into());
+    }
 
     msg!(
-        "Vesting schedule created for employee: {} in organization: {}, amount: {}",
+        "Vesting schedule created for employee: {} in organization: {}, amount: {}, transfer fee: {}",
         employee.name,
         organization.name,
-        total_amount
+        total_amount,
+        transfer_fee
     );
 
     Ok(())
 }
+
 #[derive(Accounts)]
 #[instruction(org_id: u64)]
 pub struct InitializeVestingScheduleCtx<'info> {
@@ -91,7 +116,6 @@ pub struct InitializeVestingScheduleCtx<'info> {
         seeds = [
             b"vesting_schedule",
             org_id.to_le_bytes().as_ref(),
-            employee.employee.as_ref(),
             token_mint.key().as_ref(),
             (program_state.total_vesting_schedules + 1).to_le_bytes().as_ref()
         ],
@@ -105,21 +129,34 @@ pub struct InitializeVestingScheduleCtx<'info> {
         payer = employer,
         associated_token::mint = token_mint,
         associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
     )]
-    pub vesting_token_account: Account<'info, TokenAccount>,
+    pub vesting_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = employer_token_account.mint == token_mint.key(),
         constraint = employer_token_account.owner == employer.key()
     )]
-    pub employer_token_account: Account<'info, TokenAccount>,
+    pub employer_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_mint: Account<'info, Mint>,
+    #[account(mint::token_program = token_program)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    // The owner, a Finance or Admin member, or a multisig signer executing the proposal
     #[account(mut)]
     pub employer: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::*,
     errors::ErrorCode,
-    states::{Employee, Organization, ProgramState}
+    states::{Employee, Invite, Organization, ProgramState}
 };
 
 pub fn join_organization(
@@ -18,6 +18,25 @@ pub fn join_organization(
         return Err(ErrorCode::EmployeePositionTooLong.into());
     }
 
+    // Invite-only organizations admit a wallet once, through a valid invite
+    if !ctx.accounts.organization.open_enrollment {
+        let (Some(invite), Some(invite_creator)) = (&ctx.accounts.invite, &ctx.accounts.invite_creator) else {
+            return Err(ErrorCode::InviteRequired.into());
+        };
+        let invitee_signed = if invite.is_code {
+            ctx.accounts.invite_code.as_ref().is_some_and(|invite_code| invite_code.key() == invite.invitee)
+        } else {
+            ctx.accounts.employee_signer.key() == invite.invitee
+        };
+        if invite.org_id != org_id || !invitee_signed || invite_creator.key() != invite.created_by {
+            return Err(ErrorCode::InvalidInvite.into());
+        }
+        if Clock::get()?.unix_timestamp >= invite.expires_at {
+            return Err(ErrorCode::InviteExpired.into());
+        }
+        invite.close(invite_creator.to_account_info())?;
+    }
+
     let organization = &mut ctx.accounts.organization;
     let employee = &mut ctx.accounts.employee;
     let program_state = &mut ctx.accounts.program_state;
@@ -72,5 +91,14 @@ pub struct JoinOrganizationCtx<'info> {
     
     #[account(mut)]
     pub employee_signer: Signer<'info>,
+
+    // Only for invite-only organizations
+    #[account(mut)]
+    pub invite: Option<Account<'info, Invite>>,
+    // Signature of the invite code keypair, for code invites
+    pub invite_code: Option<Signer<'info>>,
+    /// CHECK: Creator of the invite, validated in the handler; receives the invite's rent back
+    #[account(mut)]
+    pub invite_creator: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
 }
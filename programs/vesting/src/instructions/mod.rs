@@ -1,10 +1,40 @@
 pub mod initialize_program;
 pub mod create_organization;
 pub mod join_organization;
+pub mod create_invite;
+pub mod cancel_invite;
+pub mod set_open_enrollment;
 pub mod remove_employee_from_org;
+pub mod propose_org_owner;
+pub mod accept_org_owner;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
 pub mod initialize_vesting_schedule;
+pub mod initialize_native_vesting_schedule;
+pub mod create_unlock_table;
+pub mod increase_vesting_amount;
+pub mod amend_vesting_schedule;
+pub mod pause_vesting;
+pub mod resume_vesting;
+pub mod accelerate_vesting;
+pub mod accelerate_organization_vesting;
+pub mod rotate_beneficiary;
+pub mod set_beneficiary_rotation_approval;
+pub mod set_milestone_attester;
+pub mod attest_milestone;
 pub mod claim_tokens;
+pub mod set_claim_delegate;
+pub mod claim_tokens_to;
+pub mod claim_many;
+pub mod set_auto_distribute;
+pub mod configure_crank_tips;
+pub mod withdraw_crank_tips;
+pub mod crank_distribute;
 pub mod revoke_vesting;
+pub mod close_vesting_schedule;
 pub mod get_employer_dashboard;
 pub mod get_employee_dashboard;
 pub mod get_organization_employees;
@@ -17,10 +47,40 @@ pub mod get_dashboard_stats;
 pub use initialize_program::*;
 pub use create_organization::*;
 pub use join_organization::*;
+pub use create_invite::*;
+pub use cancel_invite::*;
+pub use set_open_enrollment::*;
 pub use remove_employee_from_org::*;
+pub use propose_org_owner::*;
+pub use accept_org_owner::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
 pub use initialize_vesting_schedule::*;
+pub use initialize_native_vesting_schedule::*;
+pub use create_unlock_table::*;
+pub use increase_vesting_amount::*;
+pub use amend_vesting_schedule::*;
+pub use pause_vesting::*;
+pub use resume_vesting::*;
+pub use accelerate_vesting::*;
+pub use accelerate_organization_vesting::*;
+pub use rotate_beneficiary::*;
+pub use set_beneficiary_rotation_approval::*;
+pub use set_milestone_attester::*;
+pub use attest_milestone::*;
 pub use claim_tokens::*;
+pub use set_claim_delegate::*;
+pub use claim_tokens_to::*;
+pub use claim_many::*;
+pub use set_auto_distribute::*;
+pub use configure_crank_tips::*;
+pub use withdraw_crank_tips::*;
+pub use crank_distribute::*;
 pub use revoke_vesting::*;
+pub use close_vesting_schedule::*;
 pub use get_employer_dashboard::*;
 pub use get_employee_dashboard::*;
 pub use get_organization_employees::*;
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction, VestingSchedule},
+};
+
+pub fn pause_vesting(ctx: Context<PauseVestingCtx>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::PauseVesting { vesting_schedule: vesting_schedule.key() },
+    )?;
+    if vesting_schedule.revoked {
+        return Err(ErrorCode::VestingScheduleRevoked.into());
+    }
+    if vesting_schedule.is_milestone_based() {
+        return Err(ErrorCode::PauseNotSupported.into());
+    }
+    if vesting_schedule.paused_at.is_some() {
+        return Err(ErrorCode::VestingAlreadyPaused.into());
+    }
+    // Checked up front so that resuming can always record the interval
+    if vesting_schedule.pause_intervals.len() >= MAX_PAUSE_INTERVALS {
+        return Err(ErrorCode::TooManyPauses.into());
+    }
+
+    vesting_schedule.paused_at = Some(current_time);
+
+    msg!("Vesting schedule {} paused at {}", vesting_schedule.schedule_id, current_time);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PauseVestingCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
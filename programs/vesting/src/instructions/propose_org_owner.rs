@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::states::{Organization, Proposal, ProposalAction};
+
+/// First step of an ownership transfer; `None` cancels a pending proposal.
+pub fn propose_org_owner(
+    ctx: Context<ProposeOrgOwnerCtx>,
+    _org_id: u64,
+    new_owner: Option<Pubkey>,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::ProposeOrgOwner { new_owner },
+    )?;
+
+    organization.pending_owner = new_owner;
+
+    match new_owner {
+        Some(new_owner) => msg!("Proposed {} as owner of organization '{}'", new_owner, organization.name),
+        None => msg!("Cancelled pending ownership transfer of organization '{}'", organization.name),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct ProposeOrgOwnerCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
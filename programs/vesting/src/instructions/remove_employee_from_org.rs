@@ -1,8 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{
-    errors::ErrorCode,
-    states::{Employee, Organization}
-};
+use crate::states::{Employee, OrgRole, Organization, Permission, Proposal, ProposalAction};
 
 pub fn remove_employee_from_org(
     ctx: Context<RemoveEmployeeFromOrgCtx>,
@@ -11,9 +8,13 @@ pub fn remove_employee_from_org(
     let organization = &mut ctx.accounts.organization;
     let employee = &mut ctx.accounts.employee;
 
-    if organization.owner != ctx.accounts.owner.key() {
-        return Err(ErrorCode::UnauthorizedOrganizationOwner.into());
-    }
+    organization.authorize_role_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::ManageEmployees,
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::RemoveEmployee { employee: employee.key() },
+    )?;
 
     employee.active = false;
     organization.total_employees -= 1;
@@ -43,6 +44,14 @@ pub struct RemoveEmployeeFromOrgCtx<'info> {
     )]
     pub employee: Account<'info, Employee>,
     
+    // The owner, an HR or Admin member, or a multisig signer executing the proposal
     #[account(mut)]
     pub owner: Signer<'info>,
+
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
\ No newline at end of file
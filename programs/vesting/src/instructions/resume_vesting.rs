@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction, PauseInterval, VestingSchedule},
+};
+
+pub fn resume_vesting(ctx: Context<ResumeVestingCtx>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::ResumeVesting { vesting_schedule: vesting_schedule.key() },
+    )?;
+    let Some(paused_at) = vesting_schedule.paused_at else {
+        return Err(ErrorCode::VestingNotPaused.into());
+    };
+
+    vesting_schedule.pause_intervals.push(PauseInterval {
+        start_time: paused_at,
+        end_time: current_time,
+    });
+    vesting_schedule.paused_at = None;
+
+    msg!(
+        "Vesting schedule {} resumed after {} seconds, now ending at {}",
+        vesting_schedule.schedule_id,
+        current_time - paused_at,
+        vesting_schedule.extended_end_time(current_time)
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResumeVestingCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::states::{OrgRole, Organization, Proposal, ProposalAction};
+
+pub fn revoke_role(ctx: Context<RevokeRoleCtx>) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let org_role = &ctx.accounts.org_role;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::RevokeRole { member: org_role.member },
+    )?;
+
+    msg!("Revoked {:?} role in organization '{}' from {}", org_role.role, organization.name, org_role.member);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeRoleCtx<'info> {
+    #[account(
+        seeds = [b"organization", org_role.org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [b"org_role", org_role.org_id.to_le_bytes().as_ref(), org_role.member.as_ref()],
+        bump,
+        close = owner
+    )]
+    pub org_role: Account<'info, OrgRole>,
+
+    // The owner, or a multisig signer executing the proposal
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
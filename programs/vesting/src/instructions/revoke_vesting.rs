@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
-use crate::{errors::ErrorCode, states::VestingSchedule};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    errors::ErrorCode,
+    states::{OrgRole, Organization, Permission, Proposal, ProposalAction, TerminationMode, UnlockTable, VestingSchedule},
+    utils::{calculate_transfer_fee, transfer_from_vesting_vault, transfer_lamports},
+};
 
-pub fn revoke_vesting(ctx: Context<RevokeVestingCtx>) -> Result<()> {
+pub fn revoke_vesting(ctx: Context<RevokeVestingCtx>, mode: TerminationMode) -> Result<()> {
     let vesting_schedule = &mut ctx.accounts.vesting_schedule;
     let current_time = Clock::get()?.unix_timestamp;
 
@@ -12,78 +16,113 @@ pub fn revoke_vesting(ctx: Context<RevokeVestingCtx>) -> Result<()> {
     if !vesting_schedule.revocable {
         return Err(ErrorCode::VestingScheduleNotRevocable.into());
     }
+    vesting_schedule.validate_unlock_table(ctx.accounts.unlock_table.as_ref())?;
+    ctx.accounts.organization.authorize_role_action(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role.as_deref(),
+        Permission::Revoke,
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::RevokeVesting { vesting_schedule: vesting_schedule.key(), mode },
+    )?;
 
-    let unvested_amount = vesting_schedule.calculate_unvested_amount(current_time);
-    vesting_schedule.revoked = true;
-    vesting_schedule.revoke_time = Some(current_time);
+    let unvested_amount = vesting_schedule.terminate(mode, current_time, ctx.accounts.unlock_table.as_deref())?;
 
-    if unvested_amount > 0 {
-        let org_id = vesting_schedule.org_id;
-        let employee = vesting_schedule.employee;
-        let token_mint = vesting_schedule.token_mint;
-        let schedule_id = vesting_schedule.schedule_id;
+    if vesting_schedule.native {
+        if unvested_amount > 0 {
+            transfer_lamports(
+                &vesting_schedule.to_account_info(),
+                &ctx.accounts.employer.to_account_info(),
+                unvested_amount,
+            )?;
+        }
 
-        let org_id_bytes = org_id.to_le_bytes();
-        let schedule_id_bytes = schedule_id.to_le_bytes();
-        let bump_bytes = [ctx.bumps.vesting_schedule];
+        msg!("Vesting schedule revoked. Returned {} unvested lamports to employer", unvested_amount);
+        return Ok(());
+    }
 
-        let seeds = &[
-            b"vesting_schedule",
-            org_id_bytes.as_ref(),
-            employee.as_ref(),
-            token_mint.as_ref(),
-            schedule_id_bytes.as_ref(),
-            &bump_bytes,
-        ];
-        let signer_seeds = &[&seeds[..]];
+    let (Some(vesting_token_account), Some(employer_token_account), Some(token_mint), Some(token_program)) = (
+        &ctx.accounts.vesting_token_account,
+        &ctx.accounts.employer_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+    ) else {
+        return Err(ErrorCode::TokenAccountsRequired.into());
+    };
 
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.vesting_token_account.to_account_info(),
-                to: ctx.accounts.employer_token_account.to_account_info(),
-                authority: vesting_schedule.to_account_info(),
-            },
-            signer_seeds,
-        );
-        transfer(transfer_ctx, unvested_amount)?;
+    // The vault always gives up the full unvested amount; transfer-fee mints withhold their fee from the employer's side
+    let transfer_fee = calculate_transfer_fee(&token_mint.to_account_info(), unvested_amount)?;
+    vesting_schedule.transfer_fees_withheld += transfer_fee;
+    if unvested_amount > 0 {
+        transfer_from_vesting_vault(
+            vesting_schedule,
+            ctx.bumps.vesting_schedule,
+            vesting_token_account,
+            token_mint,
+            employer_token_account.to_account_info(),
+            token_program,
+            unvested_amount,
+        )?;
     }
 
-    msg!("Vesting schedule revoked. Returned {} unvested tokens to employer", unvested_amount);
+    msg!(
+        "Vesting schedule revoked. Returned {} unvested tokens to employer ({} withheld as transfer fee)",
+        unvested_amount,
+        transfer_fee
+    );
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct RevokeVestingCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
     #[account(
         mut,
         seeds = [
             b"vesting_schedule",
             vesting_schedule.org_id.to_le_bytes().as_ref(),
-            vesting_schedule.employee.as_ref(),
             vesting_schedule.token_mint.as_ref(),
             vesting_schedule.schedule_id.to_le_bytes().as_ref()
         ],
-        bump,
-        constraint = vesting_schedule.employer == employer.key() @ ErrorCode::UnauthorizedOrganizationOwner
+        bump
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
     
+    // Token accounts are omitted for native SOL schedules
     #[account(
         mut,
-        seeds = [b"vesting_token_account", vesting_schedule.key().as_ref()],
-        bump
+        associated_token::mint = vesting_schedule.token_mint,
+        associated_token::authority = vesting_schedule,
+        associated_token::token_program = token_program,
     )]
-    pub vesting_token_account: Account<'info, TokenAccount>,
+    pub vesting_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(
         mut,
         constraint = employer_token_account.mint == vesting_schedule.token_mint,
         constraint = employer_token_account.owner == employer.key()
     )]
-    pub employer_token_account: Account<'info, TokenAccount>,
+    pub employer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
+    /// CHECK: The current organization owner, receives the unvested lamports of native SOL schedules
+    #[account(mut, address = organization.owner)]
+    pub employer: UncheckedAccount<'info>,
+    // The owner, an Admin member, or a multisig signer executing the proposal
+    pub authority: Signer<'info>,
+    #[account(address = vesting_schedule.token_mint, mint::token_program = token_program)]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Only for members acting through their role
+    pub role: Option<Account<'info, OrgRole>>,
+
+    // Only for multisig organizations
     #[account(mut)]
-    pub employer: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    // Only for table-based schedules
+    pub unlock_table: Option<Account<'info, UnlockTable>>,
 }
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction, VestingSchedule},
+};
+
+pub fn rotate_beneficiary(ctx: Context<RotateBeneficiaryCtx>, new_employee: Pubkey) -> Result<()> {
+    let organization = &ctx.accounts.organization;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    if new_employee == Pubkey::default() || new_employee == vesting_schedule.employee {
+        return Err(ErrorCode::InvalidBeneficiary.into());
+    }
+    if organization.beneficiary_rotation_requires_approval {
+        let Some(owner) = &ctx.accounts.owner else {
+            return Err(ErrorCode::UnauthorizedOrganizationOwner.into());
+        };
+        organization.authorize_owner_action(
+            &owner.key(),
+            ctx.accounts.proposal.as_deref_mut(),
+            &ProposalAction::RotateBeneficiary { vesting_schedule: vesting_schedule.key(), new_employee },
+        )?;
+    }
+
+    let previous_employee = vesting_schedule.employee;
+    vesting_schedule.employee = new_employee;
+    // The delegate was chosen by the previous beneficiary
+    vesting_schedule.claim_delegate = None;
+
+    msg!(
+        "Beneficiary of vesting schedule {} rotated from {} to {}",
+        vesting_schedule.schedule_id,
+        previous_employee,
+        new_employee
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RotateBeneficiaryCtx<'info> {
+    #[account(
+        constraint = organization.org_id == vesting_schedule.org_id @ ErrorCode::OrganizationNotFound
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = vesting_schedule.employee == employee.key() @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    pub employee: Signer<'info>,
+    // Only required when the organization requires approval for beneficiary rotation: the owner, or a
+    // multisig signer executing the proposal
+    pub owner: Option<Signer<'info>>,
+
+    // Only for multisig organizations that require approval for beneficiary rotation
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
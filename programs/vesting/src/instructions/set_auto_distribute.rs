@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{errors::ErrorCode, states::VestingSchedule};
+
+pub fn set_auto_distribute(ctx: Context<SetAutoDistributeCtx>, enabled: bool) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    vesting_schedule.auto_distribute = enabled;
+
+    msg!(
+        "Auto-distribution {} for vesting schedule {}",
+        if enabled { "enabled" } else { "disabled" },
+        vesting_schedule.schedule_id
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAutoDistributeCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = vesting_schedule.employee == employee.key() @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    pub employee: Signer<'info>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::states::{Organization, Proposal, ProposalAction};
+
+pub fn set_beneficiary_rotation_approval(
+    ctx: Context<SetBeneficiaryRotationApprovalCtx>,
+    _org_id: u64,
+    requires_approval: bool,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::SetBeneficiaryRotationApproval { requires_approval },
+    )?;
+
+    organization.beneficiary_rotation_requires_approval = requires_approval;
+
+    msg!(
+        "Beneficiary rotation for organization '{}' {} owner approval",
+        organization.name,
+        if requires_approval { "requires" } else { "no longer requires" }
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct SetBeneficiaryRotationApprovalCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{errors::ErrorCode, states::VestingSchedule};
+
+/// Registers (or with `None` removes) a key allowed to call `claim_tokens` for the employee.
+pub fn set_claim_delegate(ctx: Context<SetClaimDelegateCtx>, delegate: Option<Pubkey>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+    vesting_schedule.claim_delegate = delegate;
+
+    match delegate {
+        Some(delegate) => msg!("Claim delegate of vesting schedule {} set to {}", vesting_schedule.schedule_id, delegate),
+        None => msg!("Claim delegate of vesting schedule {} removed", vesting_schedule.schedule_id),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetClaimDelegateCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_schedule",
+            vesting_schedule.org_id.to_le_bytes().as_ref(),
+            vesting_schedule.token_mint.as_ref(),
+            vesting_schedule.schedule_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = vesting_schedule.employee == employee.key() @ ErrorCode::UnauthorizedEmployee
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    pub employee: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::states::{Organization, Proposal, ProposalAction};
+
+pub fn set_milestone_attester(
+    ctx: Context<SetMilestoneAttesterCtx>,
+    _org_id: u64,
+    attester: Pubkey,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::SetMilestoneAttester { attester },
+    )?;
+
+    organization.milestone_attester = attester;
+
+    msg!("Milestone attester for organization '{}' set to {}", organization.name, attester);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct SetMilestoneAttesterCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
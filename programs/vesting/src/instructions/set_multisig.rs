@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction},
+};
+
+/// Sets the organization's signer set and threshold; a threshold of 0 with no signers returns it to single ownership.
+pub fn set_multisig(
+    ctx: Context<SetMultisigCtx>,
+    _org_id: u64,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+
+    // Once multisig is enabled, changing it needs the current signers' approval
+    organization.authorize_owner_action(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::SetMultisig { signers: signers.clone(), threshold },
+    )?;
+
+    let distinct_signers = signers
+        .iter()
+        .enumerate()
+        .all(|(index, signer)| !signers[..index].contains(signer));
+    let disabling = threshold == 0 && signers.is_empty();
+    if !disabling
+        && (signers.len() > MAX_MULTISIG_SIGNERS
+            || !distinct_signers
+            || threshold == 0
+            || threshold as usize > signers.len())
+    {
+        return Err(ErrorCode::InvalidMultisigConfig.into());
+    }
+
+    organization.multisig_signers = signers;
+    organization.multisig_threshold = threshold;
+
+    msg!(
+        "Organization '{}' now requires {} of {} signers",
+        organization.name,
+        threshold,
+        organization.multisig_signers.len()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct SetMultisigCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub authority: Signer<'info>,
+}
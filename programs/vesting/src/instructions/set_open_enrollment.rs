@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::states::{Organization, Proposal, ProposalAction};
+
+pub fn set_open_enrollment(
+    ctx: Context<SetOpenEnrollmentCtx>,
+    _org_id: u64,
+    open_enrollment: bool,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+
+    organization.authorize_owner_action(
+        &ctx.accounts.owner.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::SetOpenEnrollment { open_enrollment },
+    )?;
+
+    organization.open_enrollment = open_enrollment;
+
+    msg!(
+        "Organization '{}' is now {}",
+        organization.name,
+        if open_enrollment { "open for enrollment" } else { "invite-only" }
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct SetOpenEnrollmentCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub owner: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
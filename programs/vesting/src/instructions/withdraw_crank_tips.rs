@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Organization, Proposal, ProposalAction},
+    utils::transfer_lamports,
+};
+
+/// Returns deposited crank tip lamports to the owner; the organization keeps its rent-exempt minimum.
+pub fn withdraw_crank_tips(ctx: Context<WithdrawCrankTipsCtx>, _org_id: u64, amount: u64) -> Result<()> {
+    ctx.accounts.organization.authorize_owner_action(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::WithdrawCrankTips { amount },
+    )?;
+
+    let organization_info = ctx.accounts.organization.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ANCHOR_DISCRIMINATOR_SIZE + Organization::INIT_SPACE);
+    if organization_info.lamports().saturating_sub(rent_exempt_minimum) < amount {
+        return Err(ErrorCode::InsufficientCrankTipBalance.into());
+    }
+    transfer_lamports(&organization_info, &ctx.accounts.owner.to_account_info(), amount)?;
+
+    msg!(
+        "Withdrew {} crank tip lamports from organization '{}'",
+        amount,
+        ctx.accounts.organization.name
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(org_id: u64)]
+pub struct WithdrawCrankTipsCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", org_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    /// CHECK: The organization owner, receives the withdrawn lamports
+    #[account(mut, address = organization.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    // The owner, or a multisig signer executing the proposal
+    pub authority: Signer<'info>,
+
+    // Only for multisig organizations
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
@@ -8,8 +8,10 @@ pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod states;
+pub mod utils;
 
 use crate::instructions::*;
+use crate::states::{AccelerationMode, Milestone, PauseInterval, ProposalAction, Role, TerminationMode, TerminationPolicy, UnlockCheckpoint};
 
 // View-only return types
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -21,6 +23,9 @@ pub struct VestingInfo {
     pub start_time: i64,
     pub cliff_time: i64,
     pub end_time: i64,
+    pub period_seconds: i64,
+    pub cliff_unlock_amount: u64,
+    pub initial_unlock_amount: u64,
     pub claimed_amount: u64,
     pub vested_amount: u64,
     pub claimable_amount: u64,
@@ -30,6 +35,21 @@ pub struct VestingInfo {
     pub employee_position: String,
     pub created_at: i64,
     pub org_id: u64,
+    pub unlock_table: Option<Pubkey>,
+    pub milestones: Vec<Milestone>,
+    pub native: bool,
+    pub top_up_amount: u64,
+    pub top_up_start_time: i64,
+    pub amendment_count: u64,
+    pub paused_at: Option<i64>,
+    pub pause_intervals: Vec<PauseInterval>,
+    pub accelerated_amount: u64,
+    pub termination_policy: TerminationPolicy,
+    pub termination_mode: Option<TerminationMode>,
+    pub auto_distribute: bool,
+    pub claim_delegate: Option<Pubkey>,
+    pub transfer_fees_withheld: u64,
+    pub last_crank_tip_time: i64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -37,10 +57,17 @@ pub struct OrganizationInfo {
     pub org_id: u64,
     pub name: String,
     pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
     pub total_employees: u64,
     pub total_vesting_schedules: u64,
     pub created_at: i64,
     pub active: bool,
+    pub milestone_attester: Pubkey,
+    pub beneficiary_rotation_requires_approval: bool,
+    pub crank_tip_lamports: u64,
+    pub multisig_signers: Vec<Pubkey>,
+    pub multisig_threshold: u8,
+    pub open_enrollment: bool,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -61,6 +88,14 @@ pub struct DashboardStats {
     pub total_vesting_schedules: u64,
 }
 
+// Per-schedule result of claim_many; schedules with nothing to claim report zero
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimSummary {
+    pub vesting_schedule: Pubkey,
+    pub claimed_amount: u64,
+    pub transfer_fee: u64,
+}
+
 #[program]
 pub mod token_vesting {
     use super::*;
@@ -86,6 +121,28 @@ pub mod token_vesting {
         instructions::join_organization(ctx, org_id, employee_name, employee_position)
     }
 
+    pub fn create_invite(
+        ctx: Context<CreateInviteCtx>,
+        org_id: u64,
+        invitee: Pubkey,
+        is_code: bool,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_invite(ctx, org_id, invitee, is_code, expires_at)
+    }
+
+    pub fn cancel_invite(ctx: Context<CancelInviteCtx>, org_id: u64, invitee: Pubkey) -> Result<()> {
+        instructions::cancel_invite(ctx, org_id, invitee)
+    }
+
+    pub fn set_open_enrollment(
+        ctx: Context<SetOpenEnrollmentCtx>,
+        org_id: u64,
+        open_enrollment: bool,
+    ) -> Result<()> {
+        instructions::set_open_enrollment(ctx, org_id, open_enrollment)
+    }
+
     pub fn remove_employee_from_org(
         ctx: Context<RemoveEmployeeFromOrgCtx>,
         org_id: u64,
@@ -93,7 +150,55 @@ pub mod token_vesting {
         instructions::remove_employee_from_org(ctx, org_id)
     }
 
+    pub fn propose_org_owner(
+        ctx: Context<ProposeOrgOwnerCtx>,
+        org_id: u64,
+        new_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_org_owner(ctx, org_id, new_owner)
+    }
+
+    pub fn accept_org_owner(ctx: Context<AcceptOrgOwnerCtx>, org_id: u64) -> Result<()> {
+        instructions::accept_org_owner(ctx, org_id)
+    }
+
+    pub fn grant_role(
+        ctx: Context<GrantRoleCtx>,
+        org_id: u64,
+        member: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        instructions::grant_role(ctx, org_id, member, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRoleCtx>) -> Result<()> {
+        instructions::revoke_role(ctx)
+    }
+
+    // Multisig Ownership
+    pub fn set_multisig(
+        ctx: Context<SetMultisigCtx>,
+        org_id: u64,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig(ctx, org_id, signers, threshold)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposalCtx>,
+        org_id: u64,
+        action: ProposalAction,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, org_id, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposalCtx>) -> Result<()> {
+        instructions::approve_proposal(ctx)
+    }
+
     // Vesting Management
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_vesting_schedule(
         ctx: Context<InitializeVestingScheduleCtx>,
         org_id: u64,
@@ -101,7 +206,12 @@ pub mod token_vesting {
         start_time: i64,
         cliff_time: i64,
         end_time: i64,
+        period_seconds: i64,
+        cliff_unlock_amount: u64,
+        initial_unlock_amount: u64,
+        milestone_amounts: Vec<u64>,
         revocable: bool,
+        termination_policy: TerminationPolicy,
     ) -> Result<()> {
         instructions::initialize_vesting_schedule(
             ctx,
@@ -110,16 +220,156 @@ pub mod token_vesting {
             start_time,
             cliff_time,
             end_time,
+            period_seconds,
+            cliff_unlock_amount,
+            initial_unlock_amount,
+            milestone_amounts,
+            revocable,
+            termination_policy,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_native_vesting_schedule(
+        ctx: Context<InitializeNativeVestingScheduleCtx>,
+        org_id: u64,
+        total_amount: u64,
+        start_time: i64,
+        cliff_time: i64,
+        end_time: i64,
+        period_seconds: i64,
+        cliff_unlock_amount: u64,
+        initial_unlock_amount: u64,
+        milestone_amounts: Vec<u64>,
+        revocable: bool,
+        termination_policy: TerminationPolicy,
+    ) -> Result<()> {
+        instructions::initialize_native_vesting_schedule(
+            ctx,
+            org_id,
+            total_amount,
+            start_time,
+            cliff_time,
+            end_time,
+            period_seconds,
+            cliff_unlock_amount,
+            initial_unlock_amount,
+            milestone_amounts,
             revocable,
+            termination_policy,
         )
     }
 
-    pub fn claim_tokens(ctx: Context<ClaimTokensCtx>) -> Result<()> {
-        instructions::claim_tokens(ctx)
+    pub fn create_unlock_table(
+        ctx: Context<CreateUnlockTableCtx>,
+        org_id: u64,
+        checkpoints: Vec<UnlockCheckpoint>,
+        interpolate: bool,
+    ) -> Result<()> {
+        instructions::create_unlock_table(ctx, org_id, checkpoints, interpolate)
+    }
+
+    pub fn increase_vesting_amount(ctx: Context<IncreaseVestingAmountCtx>, amount: u64) -> Result<()> {
+        instructions::increase_vesting_amount(ctx, amount)
+    }
+
+    pub fn amend_vesting_schedule(
+        ctx: Context<AmendVestingScheduleCtx>,
+        new_cliff_time: i64,
+        new_end_time: i64,
+    ) -> Result<()> {
+        instructions::amend_vesting_schedule(ctx, new_cliff_time, new_end_time)
+    }
+
+    pub fn pause_vesting(ctx: Context<PauseVestingCtx>) -> Result<()> {
+        instructions::pause_vesting(ctx)
+    }
+
+    pub fn resume_vesting(ctx: Context<ResumeVestingCtx>) -> Result<()> {
+        instructions::resume_vesting(ctx)
+    }
+
+    pub fn accelerate_vesting(ctx: Context<AccelerateVestingCtx>, mode: AccelerationMode) -> Result<()> {
+        instructions::accelerate_vesting(ctx, mode)
+    }
+
+    pub fn accelerate_organization_vesting<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AccelerateOrganizationVestingCtx<'info>>,
+        org_id: u64,
+        mode: AccelerationMode,
+    ) -> Result<()> {
+        instructions::accelerate_organization_vesting(ctx, org_id, mode)
+    }
+
+    pub fn rotate_beneficiary(ctx: Context<RotateBeneficiaryCtx>, new_employee: Pubkey) -> Result<()> {
+        instructions::rotate_beneficiary(ctx, new_employee)
+    }
+
+    pub fn set_beneficiary_rotation_approval(
+        ctx: Context<SetBeneficiaryRotationApprovalCtx>,
+        org_id: u64,
+        requires_approval: bool,
+    ) -> Result<()> {
+        instructions::set_beneficiary_rotation_approval(ctx, org_id, requires_approval)
+    }
+
+    pub fn set_milestone_attester(
+        ctx: Context<SetMilestoneAttesterCtx>,
+        org_id: u64,
+        attester: Pubkey,
+    ) -> Result<()> {
+        instructions::set_milestone_attester(ctx, org_id, attester)
+    }
+
+    pub fn attest_milestone(ctx: Context<AttestMilestoneCtx>, milestone_index: u8) -> Result<()> {
+        instructions::attest_milestone(ctx, milestone_index)
+    }
+
+    pub fn claim_tokens(ctx: Context<ClaimTokensCtx>, amount: Option<u64>) -> Result<()> {
+        instructions::claim_tokens(ctx, amount)
+    }
+
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegateCtx>, delegate: Option<Pubkey>) -> Result<()> {
+        instructions::set_claim_delegate(ctx, delegate)
+    }
+
+    pub fn claim_tokens_to(ctx: Context<ClaimTokensToCtx>, amount: Option<u64>) -> Result<()> {
+        instructions::claim_tokens_to(ctx, amount)
+    }
+
+    pub fn claim_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimManyCtx<'info>>,
+    ) -> Result<Vec<ClaimSummary>> {
+        instructions::claim_many(ctx)
+    }
+
+    pub fn set_auto_distribute(ctx: Context<SetAutoDistributeCtx>, enabled: bool) -> Result<()> {
+        instructions::set_auto_distribute(ctx, enabled)
+    }
+
+    pub fn configure_crank_tips(
+        ctx: Context<ConfigureCrankTipsCtx>,
+        org_id: u64,
+        tip_lamports: u64,
+        deposit_lamports: u64,
+    ) -> Result<()> {
+        instructions::configure_crank_tips(ctx, org_id, tip_lamports, deposit_lamports)
+    }
+
+    pub fn withdraw_crank_tips(ctx: Context<WithdrawCrankTipsCtx>, org_id: u64, amount: u64) -> Result<()> {
+        instructions::withdraw_crank_tips(ctx, org_id, amount)
+    }
+
+    pub fn crank_distribute(ctx: Context<CrankDistributeCtx>) -> Result<()> {
+        instructions::crank_distribute(ctx)
+    }
+
+    pub fn revoke_vesting(ctx: Context<RevokeVestingCtx>, mode: TerminationMode) -> Result<()> {
+        instructions::revoke_vesting(ctx, mode)
     }
 
-    pub fn revoke_vesting(ctx: Context<RevokeVestingCtx>) -> Result<()> {
-        instructions::revoke_vesting(ctx)
+    pub fn close_vesting_schedule(ctx: Context<CloseVestingScheduleCtx>) -> Result<()> {
+        instructions::close_vesting_schedule(ctx)
     }
 
     // Dashboard & Analytics Functions
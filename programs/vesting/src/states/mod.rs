@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::constants::*;
+use crate::{constants::*, errors::ErrorCode};
 
 #[account]
 #[derive(InitSpace)]
@@ -18,10 +18,287 @@ pub struct Organization {
     #[max_len(MAX_ORG_NAME_LENGTH)]
     pub name: String,
     pub owner: Pubkey,
+    /// Owner proposed by propose_org_owner, who still has to accept
+    pub pending_owner: Option<Pubkey>,
     pub total_employees: u64,
     pub total_vesting_schedules: u64,
     pub created_at: i64,
     pub active: bool,
+    pub total_unlock_tables: u64,
+    pub milestone_attester: Pubkey,
+    /// Whether employees need the owner's co-signature to rotate the beneficiary of a schedule
+    pub beneficiary_rotation_requires_approval: bool,
+    /// Lamports paid from this account to each crank_distribute caller
+    pub crank_tip_lamports: u64,
+    /// Signer set of a multisig organization; empty while the organization has a single owner
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub multisig_signers: Vec<Pubkey>,
+    /// Approvals a proposal needs before it can be executed, 0 for single-owner organizations
+    pub multisig_threshold: u8,
+    pub total_proposals: u64,
+    /// Lets any wallet join without an invite
+    pub open_enrollment: bool,
+}
+
+impl Organization {
+    pub fn is_multisig(&self) -> bool {
+        self.multisig_threshold > 0
+    }
+
+    pub fn is_multisig_signer(&self, key: &Pubkey) -> bool {
+        self.multisig_signers.contains(key)
+    }
+
+    /// Authorizes an owner-only action: a direct call by the owner for single-owner organizations,
+    /// or, for multisig organizations, one of the signers executing an approved proposal for exactly this action.
+    pub fn authorize_owner_action(
+        &self,
+        signer: &Pubkey,
+        proposal: Option<&mut Proposal>,
+        action: &ProposalAction,
+    ) -> Result<()> {
+        if !self.is_multisig() {
+            if self.owner != *signer {
+                return Err(ErrorCode::UnauthorizedOrganizationOwner.into());
+            }
+            return Ok(());
+        }
+
+        let Some(proposal) = proposal else {
+            return Err(ErrorCode::MultisigApprovalRequired.into());
+        };
+        if !self.is_multisig_signer(signer) {
+            return Err(ErrorCode::NotMultisigSigner.into());
+        }
+        if proposal.org_id != self.org_id || proposal.action != *action {
+            return Err(ErrorCode::ProposalActionMismatch.into());
+        }
+        if proposal.executed {
+            return Err(ErrorCode::ProposalAlreadyExecuted.into());
+        }
+        // Approvals from keys removed from the signer set since no longer count
+        let approvals = proposal.approvals
+            .iter()
+            .filter(|approver| self.is_multisig_signer(approver))
+            .count();
+        if approvals < self.multisig_threshold as usize {
+            return Err(ErrorCode::ProposalNotApproved.into());
+        }
+
+        proposal.executed = true;
+        Ok(())
+    }
+
+    /// Like `authorize_owner_action`, but single-owner organizations also accept a member whose role grants
+    /// `permission`. Multisig organizations still need an approved proposal.
+    pub fn authorize_role_action(
+        &self,
+        signer: &Pubkey,
+        role: Option<&OrgRole>,
+        permission: Permission,
+        proposal: Option<&mut Proposal>,
+        action: &ProposalAction,
+    ) -> Result<()> {
+        if !self.is_multisig() && role.is_some_and(|role| role.authorizes(self.org_id, signer, permission)) {
+            return Ok(());
+        }
+        self.authorize_owner_action(signer, proposal, action)
+    }
+
+    /// Checks `signer` is the owner or a member whose role grants `permission`.
+    pub fn authorize_member(&self, signer: &Pubkey, role: Option<&OrgRole>, permission: Permission) -> Result<()> {
+        if self.owner == *signer || role.is_some_and(|role| role.authorizes(self.org_id, signer, permission)) {
+            return Ok(());
+        }
+        Err(ErrorCode::UnauthorizedRole.into())
+    }
+
+    /// Read access to organization-wide data: the owner, multisig signers and any role holder.
+    pub fn authorize_viewer(&self, signer: &Pubkey, role: Option<&OrgRole>) -> Result<()> {
+        if self.owner == *signer
+            || self.is_multisig_signer(signer)
+            || role.is_some_and(|role| role.authorizes(self.org_id, signer, Permission::View))
+        {
+            return Ok(());
+        }
+        Err(ErrorCode::UnauthorizedRole.into())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum Role {
+    Admin,
+    Hr,
+    Finance,
+    Viewer,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    ManageEmployees,
+    FundGrants,
+    Revoke,
+    View,
+}
+
+impl Role {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        match self {
+            Role::Admin => true,
+            Role::Hr => matches!(permission, Permission::ManageEmployees | Permission::View),
+            Role::Finance => matches!(permission, Permission::FundGrants | Permission::View),
+            Role::Viewer => permission == Permission::View,
+        }
+    }
+}
+
+/// Invitation to join an organization, consumed by join_organization.
+#[account]
+#[derive(InitSpace)]
+pub struct Invite {
+    pub org_id: u64,
+    /// Invited wallet, or the public key of an invite code keypair
+    pub invitee: Pubkey,
+    /// Whether joining needs a signature from the invite code keypair rather than the invited wallet
+    pub is_code: bool,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub expires_at: i64,
+}
+
+/// Role a member holds inside an organization, granted by the owner.
+#[account]
+#[derive(InitSpace)]
+pub struct OrgRole {
+    pub org_id: u64,
+    pub member: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+}
+
+impl OrgRole {
+    pub fn authorizes(&self, org_id: u64, member: &Pubkey, permission: Permission) -> bool {
+        self.org_id == org_id && self.member == *member && self.role.has_permission(permission)
+    }
+}
+
+/// Terms of a vesting schedule as passed to initialize_vesting_schedule, recorded on proposals.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct VestingScheduleTerms {
+    pub total_amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub period_seconds: i64,
+    pub cliff_unlock_amount: u64,
+    pub initial_unlock_amount: u64,
+    #[max_len(MAX_MILESTONES)]
+    pub milestone_amounts: Vec<u64>,
+    pub revocable: bool,
+    pub termination_policy: TerminationPolicy,
+}
+
+/// Owner-only actions that need multisig approval.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    /// Native SOL schedules use the native mint as token_mint
+    InitializeVestingSchedule {
+        employee: Pubkey,
+        token_mint: Pubkey,
+        unlock_table: Option<Pubkey>,
+        terms: VestingScheduleTerms,
+    },
+    RevokeVesting {
+        vesting_schedule: Pubkey,
+        mode: TerminationMode,
+    },
+    RemoveEmployee {
+        employee: Pubkey,
+    },
+    IncreaseVestingAmount {
+        vesting_schedule: Pubkey,
+        amount: u64,
+    },
+    AccelerateVesting {
+        vesting_schedule: Pubkey,
+        mode: AccelerationMode,
+    },
+    /// Binds the schedules passed to accelerate_organization_vesting, in order, through `hash_vesting_schedules`
+    AccelerateOrganizationVesting {
+        mode: AccelerationMode,
+        schedules_hash: [u8; 32],
+    },
+    PauseVesting {
+        vesting_schedule: Pubkey,
+    },
+    ResumeVesting {
+        vesting_schedule: Pubkey,
+    },
+    AmendVestingSchedule {
+        vesting_schedule: Pubkey,
+        new_cliff_time: i64,
+        new_end_time: i64,
+    },
+    /// Only while the owner is the milestone attester
+    AttestMilestone {
+        vesting_schedule: Pubkey,
+        milestone_index: u8,
+    },
+    SetMilestoneAttester {
+        attester: Pubkey,
+    },
+    ProposeOrgOwner {
+        new_owner: Option<Pubkey>,
+    },
+    WithdrawCrankTips {
+        amount: u64,
+    },
+    SetMultisig {
+        #[max_len(MAX_MULTISIG_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    ConfigureCrankTips {
+        tip_lamports: u64,
+        deposit_lamports: u64,
+    },
+    SetOpenEnrollment {
+        open_enrollment: bool,
+    },
+    SetBeneficiaryRotationApproval {
+        requires_approval: bool,
+    },
+    /// Approves rotations while the organization requires owner approval for them
+    RotateBeneficiary {
+        vesting_schedule: Pubkey,
+        new_employee: Pubkey,
+    },
+    GrantRole {
+        member: Pubkey,
+        role: Role,
+    },
+    RevokeRole {
+        member: Pubkey,
+    },
+    /// Binds the checkpoints through `hash_unlock_checkpoints`
+    CreateUnlockTable {
+        checkpoints_hash: [u8; 32],
+        interpolate: bool,
+    },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub org_id: u64,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+    pub created_at: i64,
 }
 
 #[account]
@@ -43,51 +320,886 @@ pub struct Employee {
 pub struct VestingSchedule {
     pub org_id: u64,
     pub employer: Pubkey,
+    /// Beneficiary wallet; can be rotated, so it is not part of the PDA seeds
     pub employee: Pubkey,
+    /// Employee account the schedule was created for
+    pub employee_record: Pubkey,
     pub token_mint: Pubkey,
     pub total_amount: u64,
     pub start_time: i64,
     pub cliff_time: i64,
     pub end_time: i64,
+    pub period_seconds: i64,
+    pub cliff_unlock_amount: u64,
+    pub initial_unlock_amount: u64,
     pub claimed_amount: u64,
     pub revoked: bool,
     pub revocable: bool,
     pub revoke_time: Option<i64>,
     pub schedule_id: u64,
     pub created_at: i64,
+    pub unlock_table: Option<Pubkey>,
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    /// Native SOL schedules hold the vesting lamports in the schedule account instead of a token vault
+    pub native: bool,
+    /// Part of total_amount added by top-ups, vesting linearly from top_up_start_time to end_time
+    pub top_up_amount: u64,
+    /// Part of top_up_amount already vested when the last top-up was made
+    pub top_up_vested_base: u64,
+    pub top_up_start_time: i64,
+    pub amendment_count: u64,
+    pub paused_at: Option<i64>,
+    #[max_len(MAX_PAUSE_INTERVALS)]
+    pub pause_intervals: Vec<PauseInterval>,
+    /// Unvested amount vested early by acceleration, on top of the regular schedule
+    pub accelerated_amount: u64,
+    /// Terms applied on revocation, fixed at creation
+    pub termination_policy: TerminationPolicy,
+    pub termination_mode: Option<TerminationMode>,
+    /// Amount the employee keeps once revoked, after the termination policy is applied
+    pub vested_at_revoke: u64,
+    /// Lets anyone push claimable amounts to the employee through crank_distribute
+    pub auto_distribute: bool,
+    /// Custodian or relayer allowed to claim on the employee's behalf, into the employee's account
+    pub claim_delegate: Option<Pubkey>,
+    /// Token-2022 transfer fees withheld from claims and revocations, on top of what left the vault
+    pub transfer_fees_withheld: u64,
+    /// Last time crank_distribute paid a tip for this schedule
+    pub last_crank_tip_time: i64,
+}
+
+/// What a leaver keeps when their schedule is revoked.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct TerminationPolicy {
+    /// Months of additional vesting a good leaver is granted on top of what vested at revocation
+    pub good_leaver_extra_months: u16,
+    /// Percentage (0-100) of the vested but unclaimed amount a bad leaver forfeits
+    pub bad_leaver_forfeit_percentage: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TerminationMode {
+    GoodLeaver,
+    BadLeaver,
+}
+
+/// How much of the remaining unvested amount an acceleration vests immediately.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AccelerationMode {
+    /// Percentage (1-100) of the unvested amount
+    Percentage(u8),
+    /// Amount that would otherwise have vested over the next number of months
+    Months(u16),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PauseInterval {
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Milestone {
+    pub amount: u64,
+    pub attested_at: Option<i64>,
+}
+
+impl VestingScheduleTerms {
+    /// Checks the vesting curve parameters shared by token and native SOL schedules.
+    fn validate(&self, org_id: u64, unlock_table: Option<&UnlockTable>) -> Result<()> {
+        if self.total_amount == 0 {
+            return Err(ErrorCode::InvalidTotalAmount.into());
+        }
+        if self.start_time >= self.cliff_time || self.cliff_time >= self.end_time {
+            return Err(ErrorCode::InvalidTimeParameters.into());
+        }
+        if self.end_time - self.start_time < MIN_VESTING_DURATION {
+            return Err(ErrorCode::VestingDurationTooShort.into());
+        }
+        if self.period_seconds < 0 || self.period_seconds > self.end_time - self.cliff_time {
+            return Err(ErrorCode::InvalidVestingPeriod.into());
+        }
+        if self.cliff_unlock_amount > self.total_amount {
+            return Err(ErrorCode::InvalidCliffUnlockAmount.into());
+        }
+        match self.initial_unlock_amount.checked_add(self.cliff_unlock_amount) {
+            Some(unlocked) if unlocked <= self.total_amount => {}
+            _ => return Err(ErrorCode::InvalidInitialUnlockAmount.into()),
+        }
+        // Milestone schedules vest only through attestations, so no time-based unlocks apply
+        if !self.milestone_amounts.is_empty() {
+            let milestones_total = self.milestone_amounts
+                .iter()
+                .try_fold(0u64, |sum, amount| sum.checked_add(*amount));
+            if self.milestone_amounts.len() > MAX_MILESTONES
+                || self.milestone_amounts.contains(&0)
+                || milestones_total != Some(self.total_amount)
+                || self.period_seconds != 0
+                || self.cliff_unlock_amount != 0
+                || self.initial_unlock_amount != 0
+                || unlock_table.is_some()
+            {
+                return Err(ErrorCode::InvalidMilestones.into());
+            }
+        }
+        // Table-based schedules take their whole curve from the unlock table
+        if let Some(unlock_table) = unlock_table {
+            if unlock_table.org_id != org_id
+                || unlock_table.total_amount() != self.total_amount
+                || unlock_table.checkpoints[0].timestamp < self.start_time
+                || unlock_table.checkpoints[unlock_table.checkpoints.len() - 1].timestamp > self.end_time
+                || self.period_seconds != 0
+                || self.cliff_unlock_amount != 0
+                || self.initial_unlock_amount != 0
+            {
+                return Err(ErrorCode::InvalidUnlockTable.into());
+            }
+        }
+        if self.termination_policy.bad_leaver_forfeit_percentage > 100 {
+            return Err(ErrorCode::InvalidTerminationPolicy.into());
+        }
+        Ok(())
+    }
 }
 
 impl VestingSchedule {
-    pub fn calculate_vested_amount(&self, current_time: i64) -> u64 {
-        if self.revoked && current_time > self.revoke_time.unwrap_or(0) {
-            return self.calculate_vested_amount_at_time(self.revoke_time.unwrap_or(0));
+    /// Validates the terms and populates a newly created schedule for `employee`; the caller funds it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        &mut self,
+        schedule_id: u64,
+        employer: Pubkey,
+        employee: &Account<Employee>,
+        token_mint: Pubkey,
+        unlock_table: Option<&Account<UnlockTable>>,
+        native: bool,
+        terms: VestingScheduleTerms,
+    ) -> Result<()> {
+        terms.validate(employee.org_id, unlock_table.map(|table| &**table))?;
+        if !employee.active {
+            return Err(ErrorCode::EmployeeNotActive.into());
         }
-        self.calculate_vested_amount_at_time(current_time)
+
+        self.org_id = employee.org_id;
+        self.employer = employer;
+        self.employee = employee.employee;
+        self.employee_record = employee.key();
+        self.token_mint = token_mint;
+        self.total_amount = terms.total_amount;
+        self.start_time = terms.start_time;
+        self.cliff_time = terms.cliff_time;
+        self.end_time = terms.end_time;
+        self.period_seconds = terms.period_seconds;
+        self.cliff_unlock_amount = terms.cliff_unlock_amount;
+        self.initial_unlock_amount = terms.initial_unlock_amount;
+        self.claimed_amount = 0;
+        self.revoked = false;
+        self.revocable = terms.revocable;
+        self.termination_policy = terms.termination_policy;
+        self.termination_mode = None;
+        self.vested_at_revoke = 0;
+        self.auto_distribute = false;
+        self.claim_delegate = None;
+        self.transfer_fees_withheld = 0;
+        self.last_crank_tip_time = 0;
+        self.revoke_time = None;
+        self.schedule_id = schedule_id;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.unlock_table = unlock_table.map(|table| table.key());
+        self.milestones = terms.milestone_amounts
+            .into_iter()
+            .map(|amount| Milestone { amount, attested_at: None })
+            .collect();
+        self.native = native;
+        self.top_up_amount = 0;
+        self.top_up_vested_base = 0;
+        self.top_up_start_time = 0;
+        self.amendment_count = 0;
+        self.paused_at = None;
+        self.pause_intervals = Vec::new();
+        self.accelerated_amount = 0;
+        Ok(())
     }
 
-    fn calculate_vested_amount_at_time(&self, timestamp: i64) -> u64 {
-        if timestamp < self.cliff_time {
+    /// Ensures the unlock table supplied with a schedule is the one it was created with.
+    pub fn validate_unlock_table(&self, unlock_table: Option<&Account<UnlockTable>>) -> Result<()> {
+        if self.unlock_table != unlock_table.map(|table| table.key()) {
+            return Err(ErrorCode::UnlockTableMismatch.into());
+        }
+        Ok(())
+    }
+
+    pub fn is_claim_authority(&self, key: &Pubkey) -> bool {
+        self.employee == *key || self.claim_delegate == Some(*key)
+    }
+
+    pub fn is_milestone_based(&self) -> bool {
+        !self.milestones.is_empty()
+    }
+
+    pub fn calculate_vested_amount(&self, current_time: i64, unlock_table: Option<&UnlockTable>) -> u64 {
+        if self.revoked {
+            return self.vested_at_revoke;
+        }
+        self.calculate_vested_amount_at_time(current_time, unlock_table)
+    }
+
+    /// Revokes the schedule under the termination policy and returns the amount going back to the employer.
+    pub fn terminate(
+        &mut self,
+        mode: TerminationMode,
+        current_time: i64,
+        unlock_table: Option<&UnlockTable>,
+    ) -> Result<u64> {
+        let extra_months = self.termination_policy.good_leaver_extra_months;
+        if mode == TerminationMode::GoodLeaver && extra_months > 0 {
+            self.accelerate(AccelerationMode::Months(extra_months), current_time, unlock_table)?;
+        }
+        let mut vested_amount = self.calculate_vested_amount(current_time, unlock_table);
+        if mode == TerminationMode::BadLeaver {
+            // Only what has not been claimed yet can be forfeited
+            let unclaimed_amount = vested_amount.saturating_sub(self.claimed_amount);
+            vested_amount -= ((unclaimed_amount as u128)
+                * (self.termination_policy.bad_leaver_forfeit_percentage as u128)
+                / 100) as u64;
+        }
+
+        self.revoked = true;
+        self.revoke_time = Some(current_time);
+        self.termination_mode = Some(mode);
+        self.vested_at_revoke = vested_amount;
+        Ok(self.total_amount - vested_amount)
+    }
+
+    /// Maps a wall-clock timestamp onto vesting time by leaving out every paused interval.
+    /// Only the part of a pause after start_time counts, as nothing vests before then anyway.
+    pub fn vesting_time_at(&self, timestamp: i64) -> i64 {
+        let open_pause = self.paused_at.map(|paused_at| (paused_at, timestamp));
+        let paused_seconds: i64 = self.pause_intervals
+            .iter()
+            .map(|interval| (interval.start_time, interval.end_time))
+            .chain(open_pause)
+            .map(|(pause_start, pause_end)| (pause_end.min(timestamp) - pause_start.max(self.start_time)).max(0))
+            .sum();
+        timestamp - paused_seconds
+    }
+
+    /// End of vesting pushed out by the time spent paused so far.
+    pub fn extended_end_time(&self, current_time: i64) -> i64 {
+        self.end_time + (current_time - self.vesting_time_at(current_time))
+    }
+
+    fn calculate_vested_amount_at_time(&self, timestamp: i64, unlock_table: Option<&UnlockTable>) -> u64 {
+        let vesting_time = self.vesting_time_at(timestamp);
+        // Accelerated amounts come off the end of the schedule, so vesting simply completes sooner
+        self.calculate_scheduled_amount_at_vesting_time(vesting_time, unlock_table)
+            .saturating_add(self.accelerated_amount)
+            .min(self.total_amount)
+    }
+
+    fn calculate_scheduled_amount_at_vesting_time(&self, vesting_time: i64, unlock_table: Option<&UnlockTable>) -> u64 {
+        self.calculate_grant_vested_amount_at_time(vesting_time, unlock_table)
+            + self.calculate_top_up_vested_amount_at_time(vesting_time)
+    }
+
+    /// Immediately vests part of the remaining unvested amount and returns how much was accelerated.
+    pub fn accelerate(
+        &mut self,
+        mode: AccelerationMode,
+        current_time: i64,
+        unlock_table: Option<&UnlockTable>,
+    ) -> Result<u64> {
+        let unvested_amount = self.calculate_unvested_amount(current_time, unlock_table);
+        let amount = match mode {
+            AccelerationMode::Percentage(percentage) => {
+                if percentage == 0 || percentage > 100 {
+                    return Err(ErrorCode::InvalidAcceleration.into());
+                }
+                ((unvested_amount as u128) * (percentage as u128) / 100) as u64
+            }
+            AccelerationMode::Months(months) => {
+                if months == 0 {
+                    return Err(ErrorCode::InvalidAcceleration.into());
+                }
+                let vesting_time = self.vesting_time_at(current_time);
+                let accelerated_until = vesting_time + months as i64 * SECONDS_PER_MONTH;
+                self.calculate_scheduled_amount_at_vesting_time(accelerated_until, unlock_table)
+                    .saturating_sub(self.calculate_scheduled_amount_at_vesting_time(vesting_time, unlock_table))
+                    .min(unvested_amount)
+            }
+        };
+        self.accelerated_amount = self.accelerated_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::InvalidTotalAmount)?;
+        Ok(amount)
+    }
+
+    fn calculate_grant_vested_amount_at_time(&self, timestamp: i64, unlock_table: Option<&UnlockTable>) -> u64 {
+        if let Some(unlock_table) = unlock_table {
+            return unlock_table.calculate_vested_amount_at_time(timestamp);
+        }
+        // Milestone schedules vest tranche by tranche as they are attested
+        if self.is_milestone_based() {
+            return self.milestones
+                .iter()
+                .filter(|milestone| milestone.attested_at.is_some_and(|attested_at| attested_at <= timestamp))
+                .map(|milestone| milestone.amount)
+                .sum();
+        }
+        if timestamp < self.start_time {
             return 0;
         }
+        // The initial (TGE) unlock is available from start_time, regardless of the cliff
+        if timestamp < self.cliff_time {
+            return self.initial_unlock_amount;
+        }
+        let grant_amount = self.total_amount - self.top_up_amount;
         if timestamp >= self.end_time {
-            return self.total_amount;
+            return grant_amount;
         }
+        // The cliff lump sum unlocks at once, the remainder vests from cliff_time to end_time
+        let linear_amount = grant_amount - self.initial_unlock_amount - self.cliff_unlock_amount;
         let vesting_duration = self.end_time - self.cliff_time;
-        let elapsed_time = timestamp - self.cliff_time;
-        let vested_amount = (self.total_amount as u128)
+        let mut elapsed_time = timestamp - self.cliff_time;
+        // Periodic schedules only release at whole period boundaries
+        if self.period_seconds > 0 {
+            elapsed_time -= elapsed_time % self.period_seconds;
+        }
+        let linear_vested = (linear_amount as u128)
             .checked_mul(elapsed_time as u128)
             .unwrap()
             .checked_div(vesting_duration as u128)
             .unwrap() as u64;
-        vested_amount
+        self.initial_unlock_amount + self.cliff_unlock_amount + linear_vested
+    }
+
+    fn calculate_top_up_vested_amount_at_time(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.top_up_start_time {
+            return self.top_up_vested_base;
+        }
+        if timestamp >= self.end_time {
+            return self.top_up_amount;
+        }
+        // Periodic schedules release top-ups on the grant's period boundaries, counted from cliff_time
+        let mut vesting_time = timestamp;
+        if self.period_seconds > 0 {
+            vesting_time -= (timestamp - self.cliff_time) % self.period_seconds;
+        }
+        if vesting_time <= self.top_up_start_time {
+            return self.top_up_vested_base;
+        }
+        let vesting_duration = self.end_time - self.top_up_start_time;
+        let elapsed_time = vesting_time - self.top_up_start_time;
+        let top_up_vested = ((self.top_up_amount - self.top_up_vested_base) as u128)
+            .checked_mul(elapsed_time as u128)
+            .unwrap()
+            .checked_div(vesting_duration as u128)
+            .unwrap() as u64;
+        self.top_up_vested_base + top_up_vested
     }
 
-    pub fn calculate_claimable_amount(&self, current_time: i64) -> u64 {
-        let vested_amount = self.calculate_vested_amount(current_time);
+    /// Adds `amount` to the schedule, vesting pro-rata over what remains of the vesting period.
+    /// Earlier top-ups keep their own curve: the part vested so far is frozen into the base
+    /// and the rest is merged with the new amount on a fresh line from now to end_time.
+    pub fn add_top_up(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        let top_up_start_time = self.vesting_time_at(current_time).max(self.cliff_time);
+        self.top_up_vested_base = self.calculate_top_up_vested_amount_at_time(top_up_start_time);
+        self.top_up_start_time = top_up_start_time;
+        self.top_up_amount = self.top_up_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::InvalidTotalAmount)?;
+        self.total_amount = self.total_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::InvalidTotalAmount)?;
+        Ok(())
+    }
+
+    pub fn calculate_claimable_amount(&self, current_time: i64, unlock_table: Option<&UnlockTable>) -> u64 {
+        let vested_amount = self.calculate_vested_amount(current_time, unlock_table);
         vested_amount.saturating_sub(self.claimed_amount)
     }
 
-    pub fn calculate_unvested_amount(&self, current_time: i64) -> u64 {
-        self.total_amount.saturating_sub(self.calculate_vested_amount(current_time))
+    /// Amount a claim instruction pays out: the requested amount, or everything claimable if none is given.
+    pub fn amount_to_claim(
+        &self,
+        current_time: i64,
+        unlock_table: Option<&UnlockTable>,
+        amount: Option<u64>,
+    ) -> Result<u64> {
+        // Revoked schedules stay claimable up to the amount the leaver kept at revoke_time
+        let claimable_amount = self.calculate_claimable_amount(current_time, unlock_table);
+        if claimable_amount == 0 {
+            // Before the cliff only the initial unlock can be claimed; pauses push the cliff out
+            if self.vesting_time_at(current_time) < self.cliff_time && !self.revoked && !self.is_milestone_based() {
+                return Err(ErrorCode::CliffTimeNotReached.into());
+            }
+            return Err(ErrorCode::NoTokensAvailableToClaim.into());
+        }
+        match amount {
+            Some(amount) if amount == 0 || amount > claimable_amount => Err(ErrorCode::InvalidClaimAmount.into()),
+            Some(amount) => Ok(amount),
+            None => Ok(claimable_amount),
+        }
+    }
+
+    pub fn calculate_unvested_amount(&self, current_time: i64, unlock_table: Option<&UnlockTable>) -> u64 {
+        self.total_amount.saturating_sub(self.calculate_vested_amount(current_time, unlock_table))
+    }
+}
+
+/// Record of the terms a vesting schedule had before an amendment, kept for audit purposes.
+#[account]
+#[derive(InitSpace)]
+pub struct VestingAmendment {
+    pub vesting_schedule: Pubkey,
+    pub amendment_index: u64,
+    pub previous_cliff_time: i64,
+    pub previous_end_time: i64,
+    pub new_cliff_time: i64,
+    pub new_end_time: i64,
+    pub amended_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct UnlockCheckpoint {
+    pub timestamp: i64,
+    pub cumulative_amount: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UnlockTable {
+    pub org_id: u64,
+    pub table_id: u64,
+    pub creator: Pubkey,
+    /// Vest linearly between checkpoints instead of stepping at each one
+    pub interpolate: bool,
+    #[max_len(MAX_UNLOCK_CHECKPOINTS)]
+    pub checkpoints: Vec<UnlockCheckpoint>,
+    pub created_at: i64,
+}
+
+impl UnlockTable {
+    pub fn total_amount(&self) -> u64 {
+        self.checkpoints.last().map_or(0, |checkpoint| checkpoint.cumulative_amount)
+    }
+
+    fn calculate_vested_amount_at_time(&self, timestamp: i64) -> u64 {
+        let mut vested_amount = 0;
+        let mut previous: Option<&UnlockCheckpoint> = None;
+        for checkpoint in &self.checkpoints {
+            if timestamp < checkpoint.timestamp {
+                if let (true, Some(previous)) = (self.interpolate, previous) {
+                    let segment_amount = checkpoint.cumulative_amount - previous.cumulative_amount;
+                    let segment_duration = checkpoint.timestamp - previous.timestamp;
+                    let elapsed_time = timestamp - previous.timestamp;
+                    vested_amount += (segment_amount as u128)
+                        .checked_mul(elapsed_time as u128)
+                        .unwrap()
+                        .checked_div(segment_duration as u128)
+                        .unwrap() as u64;
+                }
+                return vested_amount;
+            }
+            vested_amount = checkpoint.cumulative_amount;
+            previous = Some(checkpoint);
+        }
+        vested_amount
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: i64 = 1_000;
+    const CLIFF: i64 = 2_000;
+    const END: i64 = 12_000;
+
+    fn schedule(total_amount: u64, start_time: i64, cliff_time: i64, end_time: i64) -> VestingSchedule {
+        VestingSchedule {
+            org_id: 1,
+            employer: Pubkey::new_unique(),
+            employee: Pubkey::new_unique(),
+            employee_record: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            total_amount,
+            start_time,
+            cliff_time,
+            end_time,
+            period_seconds: 0,
+            cliff_unlock_amount: 0,
+            initial_unlock_amount: 0,
+            claimed_amount: 0,
+            revoked: false,
+            revocable: true,
+            revoke_time: None,
+            schedule_id: 1,
+            created_at: start_time,
+            unlock_table: None,
+            milestones: Vec::new(),
+            native: false,
+            top_up_amount: 0,
+            top_up_vested_base: 0,
+            top_up_start_time: 0,
+            amendment_count: 0,
+            paused_at: None,
+            pause_intervals: Vec::new(),
+            accelerated_amount: 0,
+            termination_policy: TerminationPolicy { good_leaver_extra_months: 0, bad_leaver_forfeit_percentage: 0 },
+            termination_mode: None,
+            vested_at_revoke: 0,
+            auto_distribute: false,
+            claim_delegate: None,
+            transfer_fees_withheld: 0,
+            last_crank_tip_time: 0,
+        }
+    }
+
+    /// 10_000 tokens vesting one per second from CLIFF to END.
+    fn linear_schedule() -> VestingSchedule {
+        schedule(10_000, START, CLIFF, END)
+    }
+
+    /// 12_000 tokens vesting 1_000 per month over the 12 months after a one-month cliff.
+    fn monthly_schedule() -> VestingSchedule {
+        schedule(12_000, 0, SECONDS_PER_MONTH, 13 * SECONDS_PER_MONTH)
+    }
+
+    fn unlock_table(interpolate: bool) -> UnlockTable {
+        UnlockTable {
+            org_id: 1,
+            table_id: 1,
+            creator: Pubkey::new_unique(),
+            interpolate,
+            checkpoints: vec![
+                UnlockCheckpoint { timestamp: 3_000, cumulative_amount: 4_000 },
+                UnlockCheckpoint { timestamp: 7_000, cumulative_amount: 10_000 },
+            ],
+            created_at: START,
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn continuous_schedule_vests_linearly_from_the_cliff() {
+        let vesting_schedule = linear_schedule();
+        assert_eq!(vesting_schedule.calculate_vested_amount(START - 1, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF - 1, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 1, None), 1);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 5_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END - 1, None), 9_999);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 10_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END + 1_000, None), 10_000);
+    }
+
+    #[test]
+    fn periodic_schedule_releases_on_whole_periods() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.period_seconds = 2_500;
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 2_499, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 2_500, None), 2_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 4_999, None), 2_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 5_000, None), 5_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END - 1, None), 7_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 10_000);
+    }
+
+    #[test]
+    fn periodic_schedule_releases_the_remainder_at_end_time() {
+        // 10_000 seconds do not divide into 3_000 second periods; the last partial period unlocks at end_time
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.period_seconds = 3_000;
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 9_000, None), 9_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END - 1, None), 9_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 10_000);
+    }
+
+    #[test]
+    fn initial_unlock_is_available_from_start_and_cliff_unlock_at_the_cliff() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.initial_unlock_amount = 1_000;
+        vesting_schedule.cliff_unlock_amount = 2_000;
+        assert_eq!(vesting_schedule.calculate_vested_amount(START - 1, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(START, None), 1_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF - 1, None), 1_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF, None), 3_000);
+        // The remaining 7_000 vest linearly over the 10_000 seconds after the cliff
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 6_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 10_000);
+    }
+
+    #[test]
+    fn claims_before_the_cliff_are_limited_to_the_initial_unlock() {
+        let mut vesting_schedule = linear_schedule();
+        assert_eq!(
+            vesting_schedule.amount_to_claim(START + 500, None, None).unwrap_err(),
+            ErrorCode::CliffTimeNotReached.into()
+        );
+
+        vesting_schedule.initial_unlock_amount = 1_000;
+        assert_eq!(vesting_schedule.amount_to_claim(START + 500, None, None).unwrap(), 1_000);
+        assert_eq!(vesting_schedule.amount_to_claim(START + 500, None, Some(400)).unwrap(), 400);
+        assert_eq!(
+            vesting_schedule.amount_to_claim(START + 500, None, Some(1_001)).unwrap_err(),
+            ErrorCode::InvalidClaimAmount.into()
+        );
+        assert_eq!(
+            vesting_schedule.amount_to_claim(START + 500, None, Some(0)).unwrap_err(),
+            ErrorCode::InvalidClaimAmount.into()
+        );
+
+        vesting_schedule.claimed_amount = 1_000;
+        assert_eq!(
+            vesting_schedule.amount_to_claim(START + 500, None, None).unwrap_err(),
+            ErrorCode::CliffTimeNotReached.into()
+        );
+        assert_eq!(
+            vesting_schedule.amount_to_claim(CLIFF, None, None).unwrap_err(),
+            ErrorCode::NoTokensAvailableToClaim.into()
+        );
+        assert_eq!(vesting_schedule.amount_to_claim(CLIFF + 100, None, None).unwrap(), 90);
+    }
+
+    #[test]
+    fn unlock_table_steps_at_each_checkpoint() {
+        let vesting_schedule = linear_schedule();
+        let table = unlock_table(false);
+        assert_eq!(vesting_schedule.calculate_vested_amount(2_999, Some(&table)), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(3_000, Some(&table)), 4_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(6_999, Some(&table)), 4_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, Some(&table)), 10_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, Some(&table)), 10_000);
+    }
+
+    #[test]
+    fn unlock_table_interpolates_between_checkpoints() {
+        let vesting_schedule = linear_schedule();
+        let table = unlock_table(true);
+        // Nothing vests before the first checkpoint, even when interpolating
+        assert_eq!(vesting_schedule.calculate_vested_amount(2_999, Some(&table)), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(3_000, Some(&table)), 4_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(5_000, Some(&table)), 7_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, Some(&table)), 10_000);
+    }
+
+    #[test]
+    fn milestones_vest_once_attested() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.milestones = vec![
+            Milestone { amount: 4_000, attested_at: Some(5_000) },
+            Milestone { amount: 6_000, attested_at: None },
+        ];
+        assert_eq!(vesting_schedule.calculate_vested_amount(4_999, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(5_000, None), 4_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END + 1_000, None), 4_000);
+        // Milestone schedules have no cliff to report
+        assert_eq!(
+            vesting_schedule.amount_to_claim(START, None, None).unwrap_err(),
+            ErrorCode::NoTokensAvailableToClaim.into()
+        );
+    }
+
+    #[test]
+    fn top_up_vests_from_now_until_end_time() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.add_top_up(4_000, 7_000).unwrap();
+        assert_eq!(vesting_schedule.total_amount, 14_000);
+        // Nothing of the top-up vests retroactively
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 5_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(9_500, None), 7_500 + 2_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 14_000);
+    }
+
+    #[test]
+    fn top_up_before_the_cliff_starts_vesting_at_the_cliff() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.add_top_up(5_000, START + 500).unwrap();
+        assert_eq!(vesting_schedule.top_up_start_time, CLIFF);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF - 1, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 5_000 + 2_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 15_000);
+    }
+
+    #[test]
+    fn top_up_of_a_periodic_schedule_releases_on_the_grant_period_grid() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.period_seconds = 2_500;
+        vesting_schedule.add_top_up(4_000, 3_000).unwrap();
+        // The next grant boundary after the top-up is CLIFF + 2_500
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 2_499, None), 0);
+        assert_eq!(vesting_schedule.calculate_vested_amount(CLIFF + 2_500, None), 2_500 + 666);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 14_000);
+    }
+
+    #[test]
+    fn second_top_up_keeps_what_the_first_vested() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.add_top_up(4_000, 7_000).unwrap();
+        vesting_schedule.add_top_up(1_000, 9_000).unwrap();
+        assert_eq!(vesting_schedule.top_up_vested_base, 1_600);
+        assert_eq!(vesting_schedule.calculate_vested_amount(9_000, None), 7_000 + 1_600);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 15_000);
+    }
+
+    #[test]
+    fn pauses_leave_out_paused_time() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.pause_intervals = vec![PauseInterval { start_time: 3_000, end_time: 4_000 }];
+        assert_eq!(vesting_schedule.calculate_vested_amount(3_500, None), 1_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 4_000);
+        assert_eq!(vesting_schedule.extended_end_time(7_000), END + 1_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END, None), 9_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(END + 1_000, None), 10_000);
+
+        // An ongoing pause counts up to the current time
+        vesting_schedule.paused_at = Some(6_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(5_999, None), 2_999);
+        assert_eq!(vesting_schedule.calculate_vested_amount(8_000, None), 3_000);
+        assert_eq!(vesting_schedule.extended_end_time(8_000), END + 3_000);
+    }
+
+    #[test]
+    fn pauses_before_start_time_do_not_delay_vesting() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.pause_intervals = vec![PauseInterval { start_time: 0, end_time: START - 100 }];
+        assert_eq!(vesting_schedule.vesting_time_at(7_000), 7_000);
+
+        // Only the part after start_time is left out
+        vesting_schedule.pause_intervals = vec![PauseInterval { start_time: 0, end_time: START + 500 }];
+        assert_eq!(vesting_schedule.vesting_time_at(7_000), 6_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 4_500);
+
+        vesting_schedule.pause_intervals = Vec::new();
+        vesting_schedule.paused_at = Some(0);
+        assert_eq!(vesting_schedule.vesting_time_at(START - 1), START - 1);
+        assert_eq!(vesting_schedule.vesting_time_at(7_000), START);
+    }
+
+    #[test]
+    fn pauses_before_the_cliff_push_the_cliff_out() {
+        let mut vesting_schedule = linear_schedule();
+        vesting_schedule.pause_intervals = vec![PauseInterval { start_time: 1_500, end_time: 2_500 }];
+        assert_eq!(
+            vesting_schedule.amount_to_claim(CLIFF + 500, None, None).unwrap_err(),
+            ErrorCode::CliffTimeNotReached.into()
+        );
+        assert_eq!(
+            vesting_schedule.amount_to_claim(CLIFF + 1_000, None, None).unwrap_err(),
+            ErrorCode::NoTokensAvailableToClaim.into()
+        );
+        assert_eq!(vesting_schedule.amount_to_claim(CLIFF + 1_100, None, None).unwrap(), 100);
+    }
+
+    #[test]
+    fn percentage_acceleration_vests_part_of_the_unvested_amount() {
+        let mut vesting_schedule = linear_schedule();
+        assert_eq!(vesting_schedule.accelerate(AccelerationMode::Percentage(50), 7_000, None).unwrap(), 2_500);
+        assert_eq!(vesting_schedule.calculate_vested_amount(7_000, None), 7_500);
+        // Vesting completes early instead of exceeding the total
+        assert_eq!(vesting_schedule.calculate_vested_amount(9_500, None), 10_000);
+
+        assert_eq!(vesting_schedule.accelerate(AccelerationMode::Percentage(100), 7_000, None).unwrap(), 2_500);
+        assert_eq!(vesting_schedule.calculate_unvested_amount(7_000, None), 0);
+    }
+
+    #[test]
+    fn month_acceleration_vests_the_next_months() {
+        let mut vesting_schedule = monthly_schedule();
+        let now = 4 * SECONDS_PER_MONTH;
+        assert_eq!(vesting_schedule.calculate_vested_amount(now, None), 3_000);
+        assert_eq!(vesting_schedule.accelerate(AccelerationMode::Months(2), now, None).unwrap(), 2_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(now, None), 5_000);
+        // Accelerating past the end vests only what is left
+        assert_eq!(vesting_schedule.accelerate(AccelerationMode::Months(24), now, None).unwrap(), 7_000);
+        assert_eq!(vesting_schedule.calculate_vested_amount(now, None), 12_000);
+    }
+
+    #[test]
+    fn invalid_accelerations_are_rejected() {
+        let mut vesting_schedule = monthly_schedule();
+        for mode in [AccelerationMode::Percentage(0), AccelerationMode::Percentage(101), AccelerationMode::Months(0)] {
+            assert_eq!(
+                vesting_schedule.accelerate(mode, SECONDS_PER_MONTH, None).unwrap_err(),
+                ErrorCode::InvalidAcceleration.into()
+            );
+        }
+        assert_eq!(vesting_schedule.accelerated_amount, 0);
+    }
+
+    #[test]
+    fn good_leaver_keeps_extra_months() {
+        let mut vesting_schedule = monthly_schedule();
+        vesting_schedule.termination_policy.good_leaver_extra_months = 2;
+        let now = 4 * SECONDS_PER_MONTH;
+        assert_eq!(vesting_schedule.terminate(TerminationMode::GoodLeaver, now, None).unwrap(), 7_000);
+        assert!(vesting_schedule.revoked);
+        assert_eq!(vesting_schedule.vested_at_revoke, 5_000);
+        // Nothing more vests after revocation
+        assert_eq!(vesting_schedule.calculate_vested_amount(13 * SECONDS_PER_MONTH, None), 5_000);
+    }
+
+    #[test]
+    fn good_leaver_extra_months_are_capped_at_the_total() {
+        let mut vesting_schedule = monthly_schedule();
+        vesting_schedule.termination_policy.good_leaver_extra_months = 24;
+        assert_eq!(vesting_schedule.terminate(TerminationMode::GoodLeaver, 4 * SECONDS_PER_MONTH, None).unwrap(), 0);
+        assert_eq!(vesting_schedule.vested_at_revoke, 12_000);
+    }
+
+    #[test]
+    fn bad_leaver_forfeits_part_of_the_unclaimed_amount() {
+        let mut vesting_schedule = monthly_schedule();
+        vesting_schedule.termination_policy.good_leaver_extra_months = 2;
+        vesting_schedule.termination_policy.bad_leaver_forfeit_percentage = 50;
+        vesting_schedule.claimed_amount = 1_000;
+        let now = 4 * SECONDS_PER_MONTH;
+        // 3_000 vested, 2_000 of it unclaimed: half of that is forfeited, and no extra months apply
+        assert_eq!(vesting_schedule.terminate(TerminationMode::BadLeaver, now, None).unwrap(), 10_000);
+        assert_eq!(vesting_schedule.vested_at_revoke, 2_000);
+        assert_eq!(vesting_schedule.calculate_claimable_amount(now, None), 1_000);
+    }
+
+    #[test]
+    fn bad_leaver_cannot_forfeit_claimed_tokens() {
+        let mut vesting_schedule = monthly_schedule();
+        vesting_schedule.termination_policy.bad_leaver_forfeit_percentage = 100;
+        vesting_schedule.claimed_amount = 3_000;
+        assert_eq!(vesting_schedule.terminate(TerminationMode::BadLeaver, 4 * SECONDS_PER_MONTH, None).unwrap(), 9_000);
+        assert_eq!(vesting_schedule.vested_at_revoke, 3_000);
+    }
+
+    #[test]
+    fn terms_validation_rejects_inconsistent_unlocks() {
+        let terms = VestingScheduleTerms {
+            total_amount: 12_000,
+            start_time: 0,
+            cliff_time: SECONDS_PER_MONTH,
+            end_time: 13 * SECONDS_PER_MONTH,
+            period_seconds: 12 * SECONDS_PER_MONTH,
+            cliff_unlock_amount: 2_000,
+            initial_unlock_amount: 10_000,
+            milestone_amounts: Vec::new(),
+            revocable: true,
+            termination_policy: TerminationPolicy { good_leaver_extra_months: 0, bad_leaver_forfeit_percentage: 100 },
+        };
+        assert!(terms.validate(1, None).is_ok());
+
+        let too_long_period = VestingScheduleTerms { period_seconds: 12 * SECONDS_PER_MONTH + 1, ..terms.clone() };
+        assert_eq!(too_long_period.validate(1, None).unwrap_err(), ErrorCode::InvalidVestingPeriod.into());
+        let too_much_unlocked = VestingScheduleTerms { initial_unlock_amount: 10_001, ..terms.clone() };
+        assert_eq!(too_much_unlocked.validate(1, None).unwrap_err(), ErrorCode::InvalidInitialUnlockAmount.into());
+        // Table-based schedules take no time-based unlocks
+        let table_terms = VestingScheduleTerms {
+            total_amount: 10_000,
+            period_seconds: 0,
+            cliff_unlock_amount: 0,
+            initial_unlock_amount: 0,
+            ..terms.clone()
+        };
+        let table = unlock_table(false);
+        assert!(table_terms.validate(1, Some(&table)).is_ok());
+        assert_eq!(terms.validate(1, Some(&table)).unwrap_err(), ErrorCode::InvalidUnlockTable.into());
+        assert_eq!(table_terms.validate(2, Some(&table)).unwrap_err(), ErrorCode::InvalidUnlockTable.into());
+    }
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+};
+use anchor_spl::token_2022_extensions::transfer_fee::{harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{errors::ErrorCode, states::{UnlockCheckpoint, UnlockTable, VestingSchedule}};
+
+fn transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(mint_state.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Fee withheld when sending `amount` of a Token-2022 transfer-fee mint, zero for any other mint.
+pub fn calculate_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    match transfer_fee_config(mint)? {
+        Some(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::TransferFeeCalculationFailed.into()),
+        None => Ok(0),
+    }
+}
+
+/// Fee to add on top of `amount` so that the recipient receives exactly `amount`.
+pub fn calculate_inverse_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    match transfer_fee_config(mint)? {
+        Some(config) => config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::TransferFeeCalculationFailed.into()),
+        None => Ok(0),
+    }
+}
+
+/// Moves the fees withheld in a vault to its transfer-fee mint; Token-2022 refuses to close a vault still holding them.
+pub fn harvest_vault_transfer_fees<'info>(
+    vesting_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    if transfer_fee_config(&token_mint.to_account_info())?.is_none() {
+        return Ok(());
+    }
+    let harvest_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        HarvestWithheldTokensToMint {
+            token_program_id: token_program.to_account_info(),
+            mint: token_mint.to_account_info(),
+        },
+    );
+    harvest_withheld_tokens_to_mint(harvest_ctx, vec![vesting_token_account.to_account_info()])
+}
+
+/// Moves lamports out of an account owned by this program, such as a native SOL vesting schedule.
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_lamports = from.lamports();
+    if from_lamports < amount {
+        return Err(ErrorCode::InsufficientTokensInVestingAccount.into());
+    }
+    **from.try_borrow_mut_lamports()? = from_lamports - amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+/// Signer seeds of a vesting schedule PDA; every CPI signed by a schedule builds its seeds here.
+pub struct VestingScheduleSeeds {
+    org_id: [u8; 8],
+    token_mint: Pubkey,
+    schedule_id: [u8; 8],
+    bump: [u8; 1],
+}
+
+impl VestingScheduleSeeds {
+    pub fn new(vesting_schedule: &VestingSchedule, bump: u8) -> Self {
+        Self {
+            org_id: vesting_schedule.org_id.to_le_bytes(),
+            token_mint: vesting_schedule.token_mint,
+            schedule_id: vesting_schedule.schedule_id.to_le_bytes(),
+            bump: [bump],
+        }
+    }
+
+    /// For schedules loaded without Anchor's `bumps`, e.g. from `remaining_accounts`.
+    pub fn find(vesting_schedule: &VestingSchedule) -> Self {
+        let mut seeds = Self::new(vesting_schedule, 0);
+        let (_, bump) = Pubkey::find_program_address(&seeds.signer_seeds()[..4], &crate::ID);
+        seeds.bump = [bump];
+        seeds
+    }
+
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 5] {
+        [
+            b"vesting_schedule",
+            self.org_id.as_ref(),
+            self.token_mint.as_ref(),
+            self.schedule_id.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
+
+/// Sends `amount` out of a schedule's token vault, signing as the schedule PDA.
+pub fn transfer_from_vesting_vault<'info>(
+    vesting_schedule: &Account<'info, VestingSchedule>,
+    bump: u8,
+    vesting_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = VestingScheduleSeeds::new(vesting_schedule, bump);
+    let signer_seeds = seeds.signer_seeds();
+    let signer_seeds = &[&signer_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: vesting_token_account.to_account_info(),
+            mint: token_mint.to_account_info(),
+            to,
+            authority: vesting_schedule.to_account_info(),
+        },
+        signer_seeds,
+    );
+    transfer_checked(transfer_ctx, amount, token_mint.decimals)
+}
+
+/// Pays out a claim of `amount` and returns the transfer fee withheld from the recipient.
+///
+/// Native SOL schedules pay `wallet` in lamports straight from the schedule account. Token schedules pay
+/// `destination_token_account` from the vault, signing with `bump`. `claimed_amount` tracks what leaves the
+/// schedule, so it always matches the vault; transfer-fee mints withhold their fee on the recipient's side,
+/// and the schedule totals those fees in `transfer_fees_withheld`.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_out_claim<'info>(
+    vesting_schedule: &mut Account<'info, VestingSchedule>,
+    bump: u8,
+    vesting_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_mint: Option<&InterfaceAccount<'info, Mint>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    wallet: AccountInfo<'info>,
+    destination_token_account: Option<AccountInfo<'info>>,
+    amount: u64,
+) -> Result<u64> {
+    if vesting_schedule.native {
+        vesting_schedule.claimed_amount += amount;
+        transfer_lamports(&vesting_schedule.to_account_info(), &wallet, amount)?;
+        return Ok(0);
+    }
+
+    let (Some(vesting_token_account), Some(token_mint), Some(token_program), Some(destination_token_account)) =
+        (vesting_token_account, token_mint, token_program, destination_token_account)
+    else {
+        return Err(ErrorCode::TokenAccountsRequired.into());
+    };
+    if vesting_token_account.amount < amount {
+        return Err(ErrorCode::InsufficientTokensInVestingAccount.into());
+    }
+
+    vesting_schedule.claimed_amount += amount;
+    let transfer_fee = calculate_transfer_fee(&token_mint.to_account_info(), amount)?;
+    vesting_schedule.transfer_fees_withheld += transfer_fee;
+    transfer_from_vesting_vault(
+        vesting_schedule,
+        bump,
+        vesting_token_account,
+        token_mint,
+        destination_token_account,
+        token_program,
+        amount,
+    )?;
+    Ok(transfer_fee)
+}
+
+/// Unlock tables of table-based schedules, passed in `remaining_accounts` after the schedules.
+pub fn load_unlock_tables<'info>(remaining_accounts: &'info [AccountInfo<'info>]) -> Vec<Account<'info, UnlockTable>> {
+    remaining_accounts
+        .iter()
+        .filter_map(|info| Account::<UnlockTable>::try_from(info).ok())
+        .collect()
+}
+
+/// Finds the unlock table a table-based schedule was created with among `unlock_tables`.
+pub fn find_unlock_table<'a>(
+    vesting_schedule: &VestingSchedule,
+    unlock_tables: &'a [Account<UnlockTable>],
+) -> Result<Option<&'a UnlockTable>> {
+    let Some(table_key) = vesting_schedule.unlock_table else {
+        return Ok(None);
+    };
+    match unlock_tables.iter().find(|table| table.key() == table_key) {
+        Some(table) => Ok(Some(&**table)),
+        None => Err(ErrorCode::UnlockTableMismatch.into()),
+    }
+}
+
+/// SHA-256 over each checkpoint's little-endian `timestamp` and `cumulative_amount`, so a proposal can bind
+/// an unlock table without holding its checkpoints.
+pub fn hash_unlock_checkpoints(checkpoints: &[UnlockCheckpoint]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(checkpoints.len() * 16);
+    for checkpoint in checkpoints {
+        data.extend_from_slice(&checkpoint.timestamp.to_le_bytes());
+        data.extend_from_slice(&checkpoint.cumulative_amount.to_le_bytes());
+    }
+    hash(&data).to_bytes()
+}
+
+/// SHA-256 over the concatenated schedule keys, so a proposal can bind a list of schedules.
+pub fn hash_vesting_schedules(vesting_schedules: &[Pubkey]) -> [u8; 32] {
+    let keys: Vec<&[u8]> = vesting_schedules.iter().map(|key| key.as_ref()).collect();
+    hashv(&keys).to_bytes()
+}